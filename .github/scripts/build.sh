@@ -11,6 +11,8 @@ elif [ $PROJECT = 'escrow' ]; then
 elif [ $PROJECT = 'fundraiser' ]; then
     forc build --path $PROJECT
     forc build --path $PROJECT/tests/artifacts/asset
+    forc build --path $PROJECT/tests/artifacts/oracle
+    forc build --path NFT
 elif [ $PROJECT = 'multisig-wallet' ]; then
     forc build --path $PROJECT
 elif [ $PROJECT = 'NFT' ]; then
@@ -13,6 +13,14 @@ struct CallData {
     id: ContractId,
 }
 
+pub struct Delegation {
+    /// The amount of deposited governance tokens that has been delegated
+    /// 0 indicates that the user has not delegated
+    amount: u64,
+    /// The user who is able to vote with the delegated amount
+    delegate: Identity,
+}
+
 pub struct Proposal {
     /// Number of coins to forward
     /// Coin type is specified by the `asset` below
@@ -31,6 +39,8 @@ pub struct ProposalInfo {
     acceptance_percentage: u64,
     /// Address or contract which created the proposal
     author: Identity,
+    /// Whether the proposal has been cancelled by its author
+    cancelled: bool,
     /// Represents an end time (block height) for proposals
     /// Proposals can be voted on as long as the block height has not exceeded the deadline
     deadline: u64,
@@ -40,17 +50,46 @@ pub struct ProposalInfo {
     no_votes: u64,
     /// Data necessary to execute an arbitrary transaction.
     proposal_transaction: Proposal,
+    /// The minimum number of votes, yes and no combined, needed to execute a proposal
+    quorum: u64,
     /// The number of yes votes for a proposal
     yes_votes: u64,
 }
 
 impl ProposalInfo {
-    fn new(acceptance_percentage: u64, author: Identity, duration: u64, proposal_transaction: Proposal) -> Self {
+    fn new(acceptance_percentage: u64, author: Identity, duration: u64, proposal_transaction: Proposal, quorum: u64) -> Self {
         ProposalInfo {
-            acceptance_percentage, author, deadline: height() + duration,
+            acceptance_percentage, author, cancelled: false,
+            deadline: height() + duration,
             executed: false,
             no_votes: 0,
-            proposal_transaction, yes_votes: 0,
+            proposal_transaction, quorum, yes_votes: 0,
+        }
+    }
+}
+
+/// The stage of its lifecycle that a proposal is in
+pub enum ProposalState {
+    /// The proposal can be voted on
+    Active: (),
+    /// The proposal has been cancelled by its author
+    Cancelled: (),
+    /// Voting has ended without the proposal passing
+    Defeated: (),
+    /// The proposal has been executed
+    Executed: (),
+    /// The proposal passed but was not executed in time
+    Expired: (),
+    /// The proposal passed and is waiting until it can be executed
+    Queued: (),
+    /// The proposal passed and can be executed
+    Succeeded: (),
+}
+
+impl Eq for ProposalState {
+    fn eq(self, other: Self) -> bool {
+        match(self, other) {
+            (ProposalState::Active, ProposalState::Active) => true, (ProposalState::Cancelled, ProposalState::Cancelled) => true, (ProposalState::Defeated, ProposalState::Defeated) => true, (ProposalState::Executed, ProposalState::Executed) => true, (ProposalState::Expired, ProposalState::Expired) => true, (ProposalState::Queued, ProposalState::Queued) => true, (ProposalState::Succeeded, ProposalState::Succeeded) => true, _ => false, 
         }
     }
 }
@@ -3,24 +3,37 @@ library errors;
 pub enum CreationError {
     DurationCannotBeZero: (),
     InvalidAcceptancePercentage: (),
+    QuorumCannotBeZero: (),
 }
 
 pub enum InitializationError {
     CannotReinitialize: (),
     ContractNotInitialized: (),
+    GracePeriodCannotBeZero: (),
 }
 
 pub enum ProposalError {
+    GracePeriodElapsed: (),
     InsufficientApprovals: (),
+    NoVotesCast: (),
+    ProposalCancelled: (),
     ProposalExecuted: (),
     ProposalExpired: (),
     ProposalStillActive: (),
+    QuorumNotMet: (),
+    TimelockNotElapsed: (),
 }
 
 pub enum UserError {
+    AlreadyDelegated: (),
     AmountCannotBeZero: (),
+    CannotDelegateToSelf: (),
+    DelegatedVotesLocked: (),
+    DelegationChain: (),
     IncorrectAssetSent: (),
     InsufficientBalance: (),
     InvalidId: (),
+    NotDelegated: (),
+    NotProposalAuthor: (),
     VoteAmountCannotBeZero: (),
 }
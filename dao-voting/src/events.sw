@@ -5,6 +5,14 @@ dep data_structures;
 use std::{contract_id::ContractId, identity::Identity};
 use data_structures::ProposalInfo;
 
+pub struct CancelPropEvent {
+    /// The unique identifier for the proposal
+    id: u64,
+
+    /// The author who cancelled the proposal
+    user: Identity,
+}
+
 // TODO ename once https://github.com/FuelLabs/sway/issues/2106 is fixed
 pub struct CreatePropEvent {
     /// The unique Identifier for the proposal
@@ -14,6 +22,17 @@ pub struct CreatePropEvent {
     proposal_info: ProposalInfo,
 }
 
+pub struct DelegateEvent {
+    /// The amount of deposited governance tokens delegated
+    amount: u64,
+
+    /// The user who is able to vote with the delegated amount
+    delegate: Identity,
+
+    /// The user who delegated
+    user: Identity,
+}
+
 pub struct DepositEvent {
     /// The amount deposited
     amount: u64,
@@ -37,10 +56,27 @@ pub struct InitializeEvent {
     /// User who initialized the contract
     author: Identity,
 
+    /// Number of blocks after the timelock during which a passed proposal can be executed
+    grace_period: u64,
+
+    /// Number of blocks after the deadline before a passed proposal can be executed
+    timelock: u64,
+
     /// Contract Id of the token used for DAO governance
     token: ContractId,
 }
 
+pub struct UndelegateEvent {
+    /// The amount of governance tokens returned to the balance of the user
+    amount: u64,
+
+    /// The user who was able to vote with the delegated amount
+    delegate: Identity,
+
+    /// The user who revoked the delegation
+    user: Identity,
+}
+
 pub struct UnlockVotesEvent {
     /// The unique identifier for the proposal
     id: u64,
@@ -4,7 +4,7 @@ dep data_structures;
 
 use std::{contract_id::ContractId, identity::Identity};
 
-use data_structures::{Proposal, ProposalInfo, Votes};
+use data_structures::{Delegation, Proposal, ProposalInfo, ProposalState, Votes};
 
 abi DaoVoting {
     /// Initialize the dao with the governance token, voting parameters, and the proposal.
@@ -12,11 +12,14 @@ abi DaoVoting {
     /// # Arguments
     ///
     /// - `token` - contract id of the token used to vote on governance proposals
+    /// - `timelock` - number of blocks after the deadline of a proposal before it can be executed
+    /// - `grace_period` - number of blocks after the timelock during which a proposal can be executed
     ///
     /// # Reverts
     ///
     /// * When the constructor is called more than once
-    #[storage(read, write)] fn constructor(gov_token: ContractId);
+    /// * When the grace period is 0
+    #[storage(read, write)] fn constructor(gov_token: ContractId, timelock: u64, grace_period: u64);
 
     /// Create a new proposal
     ///
@@ -24,6 +27,7 @@ abi DaoVoting {
     ///
     /// - `acceptance_percentage` - the percentage of yes votes a proposal needs to be executed
     /// - `duration` - the number of blocks during which a proposal can be voted on
+    /// - `quorum` - the minimum number of votes, yes and no combined, a proposal needs to be executed
     /// - `proposal_data` - transaction data to be executed if proposal is approved
     ///
     /// # Reverts
@@ -31,7 +35,24 @@ abi DaoVoting {
     /// * When the duration is 0
     /// * When the acceptance percentage is 0
     /// * When the acceptance percentage is greater than 100
-    #[storage(read, write)]fn create_proposal(acceptance_percentage: u64, duration: u64, proposal_transaction: Proposal);
+    /// * When the quorum is 0
+    #[storage(read, write)]fn create_proposal(acceptance_percentage: u64, duration: u64, quorum: u64, proposal_transaction: Proposal);
+
+    /// Cancel a proposal so that it can no longer be voted on or executed
+    ///
+    /// Votes cast on a cancelled proposal can be unlocked immediately
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - Identifier used to specify a proposal (0 <= proposal_id < proposal_count)
+    ///
+    /// # Reverts
+    ///
+    /// * When the given proposal id is greater than or equal to proposal_count
+    /// * When the caller is not the author of the proposal
+    /// * When the proposal has already been cancelled
+    /// * When the proposal has passed its deadline
+    #[storage(read, write)]fn cancel_proposal(proposal_id: u64);
 
     /// Deposit governance tokens into contract
     ///
@@ -61,6 +82,8 @@ abi DaoVoting {
 
     /// Vote on a given proposal
     ///
+    /// Votes are cast with the user's own deposited balance before the balance delegated to them
+    ///
     /// # Arguments
     ///
     /// - `approve` - whether the user voted yes or no on the proposal
@@ -71,8 +94,9 @@ abi DaoVoting {
     ///
     /// * When the given proposal id is greater than or equal to proposal_count
     /// * When the vote amount is 0
+    /// * When the proposal has been cancelled
     /// * When the proposal has passed its deadline
-    /// * When the vote amount is greater than the user's deposited balance
+    /// * When the vote amount is greater than the user's deposited and delegated balance
     #[storage(read, write)]fn vote(approve: bool, proposal_id: u64, vote_amount: u64);
 
     /// Execute a given proposal
@@ -85,8 +109,13 @@ abi DaoVoting {
     ///
     /// * When the given proposal id is greater than or equal to proposal_count
     /// * When the proposal has already been executed
+    /// * When the proposal has been cancelled
     /// * When the proposal is still active and being voted on
+    /// * When no votes have been cast on the proposal
+    /// * When the proposal has not reached its quorum
     /// * When the proposal has not met the necessary approval percentage
+    /// * When the timelock after the deadline has not elapsed
+    /// * When the grace period after the timelock has elapsed
     #[storage(read, write)] fn execute(proposal_id: u64);
 
     /// Unlock governance tokens from a proposal
@@ -95,16 +124,52 @@ abi DaoVoting {
     /// This is to ensure a user cannot vote twice on a proposal with the same governance token.
     /// As 1 token = 1 vote.
     /// If the user did not vote on the proposal then nothing happens
+    /// Votes cast with delegated tokens are returned to the delegated balance of the user
+    /// Votes cast on a cancelled proposal can be unlocked without waiting for the deadline
+    /// Anyone can unlock the votes of any user once the proposal is no longer active
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - Identifier used to specifiy a proposal (0 <= proposal_id < proposal_count)
+    /// - `user` - Identity of the user whose votes are unlocked
     ///
     /// # Reverts
     ///
     /// * When the given proposal id is greater than or equal to proposal_count
     /// * When the proposal is still active
-    #[storage(read, write)] fn unlock_votes(proposal_id: u64);
+    #[storage(read, write)] fn unlock_votes(proposal_id: u64, user: Identity);
+
+    /// Delegate the deposited governance tokens of the user to another user
+    ///
+    /// The entire unlocked balance of the user is moved into the delegated balance of `to`, who
+    /// is then able to vote with it.
+    /// The user can no longer vote or withdraw with the delegated tokens which prevents them from
+    /// being counted twice.
+    /// Delegations cannot be chained therefore a user who has delegated cannot be delegated to and
+    /// a user who has been delegated to cannot delegate
+    ///
+    /// # Arguments
+    ///
+    /// - `to` - the user who is able to vote with the delegated tokens
+    ///
+    /// # Reverts
+    ///
+    /// * When the user has already delegated
+    /// * When the user delegates to themselves
+    /// * When `to` has delegated or the user has been delegated to
+    /// * When the user does not have a deposited balance
+    #[storage(read, write)] fn delegate(to: Identity);
+
+    /// Revoke the delegation of the user and return the delegated tokens to their balance
+    ///
+    /// Votes cast with delegated tokens are shared by every user who delegated to the delegate, so
+    /// none of them can undelegate until those votes have been unlocked after the proposal has ended
+    ///
+    /// # Reverts
+    ///
+    /// * When the user has not delegated
+    /// * When the delegate has used delegated tokens to vote on a proposal and not unlocked them
+    #[storage(read, write)] fn undelegate();
 
     /// Return the amount of governance tokens in this contract
     #[storage(read)] fn balance() -> u64;
@@ -116,6 +181,22 @@ abi DaoVoting {
     /// - `user` - Identity to look up governance token balance in this contract.
     #[storage(read)] fn user_balance(user: Identity) -> u64;
 
+    /// Return the amount of governance tokens delegated to a user which they have not used to vote
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - Identity to look up the delegated balance in this contract.
+    #[storage(read)] fn delegated_balance(user: Identity) -> u64;
+
+    /// Return the delegation made by a user
+    ///
+    /// An amount of 0 indicates that the user has not delegated
+    ///
+    /// # Arguments
+    ///
+    /// - `user` - Identity to look up the delegation of
+    #[storage(read)] fn delegation(user: Identity) -> Delegation;
+
     /// Return the amount of votes a user has used on a proposal
     ///
     /// # Arguments
@@ -135,6 +216,17 @@ abi DaoVoting {
     /// * When the given proposal id is greater than or equal to proposal_count
     #[storage(read)] fn proposal(id: u64) -> ProposalInfo;
 
+    /// Return the state of a proposal at the current block height
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - Identifier used to specify a proposal (0 <= proposal_id < proposal_count)
+    ///
+    /// # Reverts
+    ///
+    /// * When the given proposal id is greater than or equal to proposal_count
+    #[storage(read)] fn proposal_state(proposal_id: u64) -> ProposalState;
+
     /// Return governance token id
     ///
     /// # Reverts
@@ -144,4 +236,10 @@ abi DaoVoting {
 
     /// Return proposal count
     #[storage(read)] fn proposal_count() -> u64;
+
+    /// Return the number of blocks after the deadline of a proposal before it can be executed
+    #[storage(read)] fn timelock() -> u64;
+
+    /// Return the number of blocks after the timelock during which a proposal can be executed
+    #[storage(read)] fn grace_period() -> u64;
 }
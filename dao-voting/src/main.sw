@@ -8,37 +8,56 @@ dep utils;
 
 use std::{
     address::Address,
-    block::height,
     chain::auth::{AuthError, msg_sender},
     context::{call_frames::msg_asset_id, msg_amount, this_balance},
     contract_id::ContractId,
     identity::Identity,
     logging::log,
     result::Result,
-    revert::{require, revert},
+    revert::require,
     storage::StorageMap,
     token::transfer,
 };
 
 use interface::DaoVoting;
-use data_structures::{Proposal, ProposalInfo, State, Votes};
+use data_structures::{Delegation, Proposal, ProposalInfo, ProposalState, State, Votes};
 use errors::{CreationError, InitializationError, ProposalError, UserError};
 use events::{
+    CancelPropEvent,
     CreatePropEvent,
+    DelegateEvent,
     DepositEvent,
     ExecuteEvent,
     InitializeEvent,
+    UndelegateEvent,
     UnlockVotesEvent,
     VoteEvent,
     WithdrawEvent,
 };
 
-use utils::validate_id;
+use utils::{current_state, tally, validate_id};
 
 storage {
     /// The amount of governance tokens a user has deposited
     balances: StorageMap<Identity,
     u64> = StorageMap {
+    }, /// The amount of governance tokens delegated to a user which they have not used to vote
+    delegated_balances: StorageMap<Identity,
+    u64> = StorageMap {
+    }, /// The amount of delegated governance tokens a user has used to vote on a proposal
+    delegated_votes: StorageMap<(Identity,
+    u64), u64> = StorageMap {
+    }, /// The delegation made by a user
+    delegations: StorageMap<Identity,
+    Delegation> = StorageMap {
+    }, /// The number of users who have delegated to a user
+    delegator_count: StorageMap<Identity,
+    u64> = StorageMap {
+    }, /// Number of blocks after the timelock during which a passed proposal can be executed
+    grace_period: u64 = 0,
+    /// The amount of delegated governance tokens a user has used to vote which have not been unlocked
+    locked_delegated_votes: StorageMap<Identity,
+    u64> = StorageMap {
     }, /// Information describing a proposal created via create_proposal(...)
     proposals: StorageMap<u64,
     ProposalInfo> = StorageMap {
@@ -48,6 +67,8 @@ storage {
     proposal_count: u64 = 0,
     /// The initilization state of the contract.
     state: State = State::NotInitialized,
+    /// Number of blocks after the deadline of a proposal before it can be executed
+    timelock: u64 = 0,
     /// Contract Id of the governance token
     token: ContractId = ContractId {
         value: 0x0000000000000000000000000000000000000000000000000000000000000000,
@@ -59,23 +80,27 @@ storage {
 }
 
 impl DaoVoting for Contract {
-    #[storage(read, write)]fn constructor(token: ContractId) {
+    #[storage(read, write)]fn constructor(token: ContractId, timelock: u64, grace_period: u64) {
         require(storage.state == State::NotInitialized, InitializationError::CannotReinitialize);
+        require(0 < grace_period, InitializationError::GracePeriodCannotBeZero);
 
         storage.token = token;
+        storage.timelock = timelock;
+        storage.grace_period = grace_period;
         storage.state = State::Initialized;
 
         log(InitializeEvent {
-            author: msg_sender().unwrap(), token
+            author: msg_sender().unwrap(), grace_period, timelock, token
         })
     }
 
-    #[storage(read, write)]fn create_proposal(acceptance_percentage: u64, duration: u64, proposal_transaction: Proposal) {
+    #[storage(read, write)]fn create_proposal(acceptance_percentage: u64, duration: u64, quorum: u64, proposal_transaction: Proposal) {
         require(0 < duration, CreationError::DurationCannotBeZero);
         require(0 < acceptance_percentage && acceptance_percentage <= 100, CreationError::InvalidAcceptancePercentage);
+        require(0 < quorum, CreationError::QuorumCannotBeZero);
 
         let author = msg_sender().unwrap();
-        let proposal = ~ProposalInfo::new(acceptance_percentage, author, duration, proposal_transaction);
+        let proposal = ~ProposalInfo::new(acceptance_percentage, author, duration, proposal_transaction, quorum);
         storage.proposals.insert(storage.proposal_count, proposal);
         storage.proposal_count += 1;
 
@@ -84,6 +109,25 @@ impl DaoVoting for Contract {
         });
     }
 
+    #[storage(read, write)]fn cancel_proposal(proposal_id: u64) {
+        validate_id(proposal_id, storage.proposal_count);
+
+        let mut proposal = storage.proposals.get(proposal_id);
+        let user = msg_sender().unwrap();
+        require(proposal.author == user, UserError::NotProposalAuthor);
+
+        let state = current_state(proposal, storage.timelock, storage.grace_period);
+        require(state != ProposalState::Cancelled, ProposalError::ProposalCancelled);
+        require(state == ProposalState::Active, ProposalError::ProposalExpired);
+
+        proposal.cancelled = true;
+        storage.proposals.insert(proposal_id, proposal);
+
+        log(CancelPropEvent {
+            id: proposal_id, user
+        });
+    }
+
     #[storage(read, write)]fn deposit() {
         require(storage.state == State::Initialized, InitializationError::ContractNotInitialized);
         require(storage.token == msg_asset_id(), UserError::IncorrectAssetSent);
@@ -120,12 +164,22 @@ impl DaoVoting for Contract {
         require(0 < vote_amount, UserError::VoteAmountCannotBeZero);
 
         let mut proposal = storage.proposals.get(proposal_id);
-        require(height() <= proposal.deadline, ProposalError::ProposalExpired);
+        let state = current_state(proposal, storage.timelock, storage.grace_period);
+        require(state != ProposalState::Cancelled, ProposalError::ProposalCancelled);
+        require(state == ProposalState::Active, ProposalError::ProposalExpired);
 
         let user = msg_sender().unwrap();
         let user_balance = storage.balances.get(user);
+        let delegated_balance = storage.delegated_balances.get(user);
+
+        require(vote_amount <= user_balance + delegated_balance, UserError::InsufficientBalance);
 
-        require(vote_amount <= user_balance, UserError::InsufficientBalance);
+        // Use the user's own balance before the balance delegated to them
+        let delegated_amount = if user_balance < vote_amount {
+            vote_amount - user_balance
+        } else {
+            0
+        };
 
         let mut votes = storage.votes.get((user, proposal_id));
         if approve {
@@ -136,7 +190,10 @@ impl DaoVoting for Contract {
             votes.no_votes += vote_amount;
         };
 
-        storage.balances.insert(user, user_balance - vote_amount);
+        storage.balances.insert(user, user_balance - (vote_amount - delegated_amount));
+        storage.delegated_balances.insert(user, delegated_balance - delegated_amount);
+        storage.delegated_votes.insert((user, proposal_id), storage.delegated_votes.get((user, proposal_id)) + delegated_amount);
+        storage.locked_delegated_votes.insert(user, storage.locked_delegated_votes.get(user) + delegated_amount);
         storage.votes.insert((user, proposal_id), votes);
         storage.proposals.insert(proposal_id, proposal);
 
@@ -149,20 +206,32 @@ impl DaoVoting for Contract {
         validate_id(proposal_id, storage.proposal_count);
 
         let mut proposal = storage.proposals.get(proposal_id);
-        require(!proposal.executed, ProposalError::ProposalExecuted);
-        require(proposal.deadline < height(), ProposalError::ProposalStillActive);
+        let state = current_state(proposal, storage.timelock, storage.grace_period);
+        require(state != ProposalState::Executed, ProposalError::ProposalExecuted);
+        require(state != ProposalState::Cancelled, ProposalError::ProposalCancelled);
+        require(state != ProposalState::Active, ProposalError::ProposalStillActive);
+
+        // Revert with the reason that the proposal has been defeated
+        let acceptance_percentage = match tally(proposal) {
+            Result::Ok(acceptance_percentage) => acceptance_percentage, Result::Err(error) => {
+                require(false, error);
+                0
+            },
+        };
 
-        // TODO figure out how to prevent approval percentage from overflowing
-        // When close to the u64 max
-        // https://github.com/FuelLabs/sway-applications/issues/106
-        let acceptance_percentage = proposal.yes_votes * 100 / (proposal.yes_votes + proposal.no_votes);
-        require(proposal.acceptance_percentage <= acceptance_percentage, ProposalError::InsufficientApprovals);
+        require(state != ProposalState::Queued, ProposalError::TimelockNotElapsed);
+        require(state != ProposalState::Expired, ProposalError::GracePeriodElapsed);
 
         proposal.executed = true;
         storage.proposals.insert(proposal_id, proposal);
 
-        asm(call_data: proposal.proposal_transaction.call_data, amount: proposal.proposal_transaction.amount, asset: proposal.proposal_transaction.asset, gas: proposal.proposal_transaction.gas) {
-            call call_data amount asset gas;
+        // The `call` instruction expects the contract id followed by the function selector and the
+        // arguments which is not the order in which the fields of `CallData` are laid out
+        let call_data = proposal.proposal_transaction.call_data;
+        let call_frame = (call_data.id, call_data.function_selector, call_data.arguments);
+
+        asm(call_frame: call_frame, amount: proposal.proposal_transaction.amount, asset: proposal.proposal_transaction.asset, gas: proposal.proposal_transaction.gas) {
+            call call_frame amount asset gas;
         }
 
         // Users can now convert their votes back into tokens
@@ -171,27 +240,84 @@ impl DaoVoting for Contract {
         })
     }
 
-    #[storage(read, write)]fn unlock_votes(proposal_id: u64) {
+    #[storage(read, write)]fn unlock_votes(proposal_id: u64, user: Identity) {
         validate_id(proposal_id, storage.proposal_count);
 
         let proposal = storage.proposals.get(proposal_id);
-        require(proposal.deadline < height(), ProposalError::ProposalStillActive);
+        require(current_state(proposal, storage.timelock, storage.grace_period) != ProposalState::Active, ProposalError::ProposalStillActive);
 
-        let user: Identity = msg_sender().unwrap();
+        // Anyone may unlock the votes of a user once the proposal has ended so that a delegate
+        // cannot keep the tokens of their delegators locked by never unlocking
         let votes = storage.votes.get((user, proposal_id));
 
         storage.votes.insert((user, proposal_id), Votes {
             no_votes: 0, yes_votes: 0
         });
 
+        // Votes cast with delegated tokens return to the delegated balance
+        let delegated_amount = storage.delegated_votes.get((user, proposal_id));
+        storage.delegated_votes.insert((user, proposal_id), 0);
+        storage.locked_delegated_votes.insert(user, storage.locked_delegated_votes.get(user) - delegated_amount);
+
         let vote_amount = votes.yes_votes + votes.no_votes;
-        storage.balances.insert(user, storage.balances.get(user) + vote_amount);
+        storage.balances.insert(user, storage.balances.get(user) + vote_amount - delegated_amount);
+        storage.delegated_balances.insert(user, storage.delegated_balances.get(user) + delegated_amount);
 
         log(UnlockVotesEvent {
             id: proposal_id, user, vote_amount, 
         });
     }
 
+    #[storage(read, write)]fn delegate(to: Identity) {
+        let user = msg_sender().unwrap();
+
+        require(storage.delegations.get(user).amount == 0, UserError::AlreadyDelegated);
+        require(user != to, UserError::CannotDelegateToSelf);
+
+        // Chained delegations would count the same tokens towards multiple delegates
+        require(storage.delegations.get(to).amount == 0, UserError::DelegationChain);
+        require(storage.delegator_count.get(user) == 0, UserError::DelegationChain);
+
+        let amount = storage.balances.get(user);
+        require(0 < amount, UserError::AmountCannotBeZero);
+
+        storage.balances.insert(user, 0);
+        storage.delegated_balances.insert(to, storage.delegated_balances.get(to) + amount);
+        storage.delegations.insert(user, Delegation {
+            amount, delegate: to
+        });
+        storage.delegator_count.insert(to, storage.delegator_count.get(to) + 1);
+
+        log(DelegateEvent {
+            amount, delegate: to, user
+        });
+    }
+
+    #[storage(read, write)]fn undelegate() {
+        let user = msg_sender().unwrap();
+
+        let delegation = storage.delegations.get(user);
+        require(0 < delegation.amount, UserError::NotDelegated);
+
+        // Votes cast with delegated tokens are backed by every delegator in proportion to the amount
+        // they have delegated so each delegator has a share of those votes until they are unlocked.
+        // Only checking the pooled balance would let a delegator withdraw against the unused share
+        // of another delegator and vote again with tokens that have already been counted
+        require(storage.locked_delegated_votes.get(delegation.delegate) == 0, UserError::DelegatedVotesLocked);
+
+        let delegated_balance = storage.delegated_balances.get(delegation.delegate);
+        storage.delegated_balances.insert(delegation.delegate, delegated_balance - delegation.amount);
+        storage.delegator_count.insert(delegation.delegate, storage.delegator_count.get(delegation.delegate) - 1);
+        storage.delegations.insert(user, Delegation {
+            amount: 0, delegate: delegation.delegate
+        });
+        storage.balances.insert(user, storage.balances.get(user) + delegation.amount);
+
+        log(UndelegateEvent {
+            amount: delegation.amount, delegate: delegation.delegate, user
+        });
+    }
+
     #[storage(read)]fn balance() -> u64 {
         this_balance(storage.token)
     }
@@ -200,6 +326,14 @@ impl DaoVoting for Contract {
         storage.balances.get(user)
     }
 
+    #[storage(read)]fn delegated_balance(user: Identity) -> u64 {
+        storage.delegated_balances.get(user)
+    }
+
+    #[storage(read)]fn delegation(user: Identity) -> Delegation {
+        storage.delegations.get(user)
+    }
+
     #[storage(read)]fn user_votes(proposal_id: u64, user: Identity) -> Votes {
         validate_id(proposal_id, storage.proposal_count);
         storage.votes.get((user, proposal_id))
@@ -210,6 +344,11 @@ impl DaoVoting for Contract {
         storage.proposals.get(proposal_id)
     }
 
+    #[storage(read)]fn proposal_state(proposal_id: u64) -> ProposalState {
+        validate_id(proposal_id, storage.proposal_count);
+        current_state(storage.proposals.get(proposal_id), storage.timelock, storage.grace_period)
+    }
+
     #[storage(read)] fn governance_token_id() -> ContractId {
         require(storage.state == State::Initialized, InitializationError::ContractNotInitialized);
         storage.token
@@ -218,4 +357,12 @@ impl DaoVoting for Contract {
     #[storage(read)] fn proposal_count() -> u64 {
         storage.proposal_count
     }
+
+    #[storage(read)] fn timelock() -> u64 {
+        storage.timelock
+    }
+
+    #[storage(read)] fn grace_period() -> u64 {
+        storage.grace_period
+    }
 }
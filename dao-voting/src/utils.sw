@@ -1,10 +1,67 @@
 library utils;
 
+dep data_structures;
 dep errors;
 
-use std::revert::require;
-use errors::UserError;
+use std::{block::height, result::Result, revert::require, u128::U128};
+use data_structures::{ProposalInfo, ProposalState};
+use errors::{ProposalError, UserError};
+
+/// Returns the state of the proposal at the current block height
+///
+/// A passed proposal is queued for `timelock` blocks after its deadline and then expires if it
+/// has not been executed within `grace_period` blocks
+pub fn current_state(proposal: ProposalInfo, timelock: u64, grace_period: u64) -> ProposalState {
+    if proposal.cancelled {
+        ProposalState::Cancelled
+    } else if proposal.executed {
+        ProposalState::Executed
+    } else if height() <= proposal.deadline {
+        ProposalState::Active
+    } else {
+        match tally(proposal) {
+            Result::Ok(_) => {
+                // Compare the blocks elapsed since the deadline so that large values of
+                // `timelock` and `grace_period` cannot overflow
+                let elapsed = height() - proposal.deadline;
+                if elapsed <= timelock {
+                    ProposalState::Queued
+                } else if elapsed - timelock <= grace_period {
+                    ProposalState::Succeeded
+                } else {
+                    ProposalState::Expired
+                }
+            },
+            Result::Err(_) => ProposalState::Defeated,
+        }
+    }
+}
+
+/// Returns `amount * numerator / denominator` without overflowing the intermediate product
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> u64 {
+    let result = ~U128::from(0, amount) * ~U128::from(0, numerator) / ~U128::from(0, denominator);
+    result.as_u64().unwrap()
+}
 
 pub fn validate_id(id: u64, count: u64) {
     require(id < count, UserError::InvalidId);
 }
+
+/// Returns the percentage of votes in favour of the proposal or the reason it has been defeated
+pub fn tally(proposal: ProposalInfo) -> Result<u64, ProposalError> {
+    // Every vote is backed by a deposited token so the total cannot exceed the token supply
+    let total_votes = proposal.yes_votes + proposal.no_votes;
+    if total_votes == 0 {
+        return Result::Err(ProposalError::NoVotesCast);
+    }
+    if total_votes < proposal.quorum {
+        return Result::Err(ProposalError::QuorumNotMet);
+    }
+
+    let acceptance_percentage = mul_div(proposal.yes_votes, 100, total_votes);
+    if acceptance_percentage < proposal.acceptance_percentage {
+        return Result::Err(ProposalError::InsufficientApprovals);
+    }
+
+    Result::Ok(acceptance_percentage)
+}
@@ -4,6 +4,7 @@ use std::{address::Address, context::call_frames::contract_id, token::mint_to_ad
 
 abi GovToken {
     fn mint_and_send_to_address(amount: u64, recipient: Address) -> bool;
+    fn proposal_target() -> bool;
 }
 
 impl GovToken for Contract {
@@ -11,4 +12,8 @@ impl GovToken for Contract {
         mint_to_address(amount, recipient);
         true
     }
+
+    fn proposal_target() -> bool {
+        true
+    }
 }
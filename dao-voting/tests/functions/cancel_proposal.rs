@@ -0,0 +1,74 @@
+use crate::utils::{
+    abi_calls::{cancel_proposal, constructor, create_proposal, proposal, proposal_state},
+    test_helpers::{proposal_transaction, setup},
+    ProposalState,
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn author_can_cancel_proposal() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        cancel_proposal(&user.dao_voting, 0).await;
+
+        assert!(proposal(&user.dao_voting, 0).await.cancelled);
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Cancelled()
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_invalid_proposal_id() {
+        let (_gov_token, _gov_token_id, _deployer, user, _asset_amount) = setup().await;
+        cancel_proposal(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_when_not_author() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        cancel_proposal(&deployer.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_cancelled_proposal() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        cancel_proposal(&user.dao_voting, 0).await;
+        cancel_proposal(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_expired_proposal() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        cancel_proposal(&user.dao_voting, 0).await;
+    }
+}
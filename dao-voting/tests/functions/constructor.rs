@@ -1,5 +1,5 @@
 use crate::utils::{
-    abi_calls::{constructor, governance_token_id},
+    abi_calls::{constructor, governance_token_id, grace_period, timelock},
     test_helpers::setup,
 };
 
@@ -9,12 +9,20 @@ mod success {
     #[tokio::test]
     async fn constructs() {
         let (_gov_token, gov_token_id, deployer, _user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
         assert_eq!(
             governance_token_id(&deployer.dao_voting).await,
             gov_token_id
         );
     }
+
+    #[tokio::test]
+    async fn constructs_with_timelock_and_grace_period() {
+        let (_gov_token, gov_token_id, deployer, _user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 5, 20).await;
+        assert_eq!(timelock(&deployer.dao_voting).await, 5);
+        assert_eq!(grace_period(&deployer.dao_voting).await, 20);
+    }
 }
 
 mod revert {
@@ -24,7 +32,14 @@ mod revert {
     #[should_panic]
     async fn panics_when_reinitialized() {
         let (_gov_token, gov_token_id, deployer, _user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_with_zero_grace_period() {
+        let (_gov_token, gov_token_id, deployer, _user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 0).await;
     }
 }
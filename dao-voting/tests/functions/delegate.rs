@@ -0,0 +1,236 @@
+use crate::utils::{
+    abi_calls::{constructor, delegate, delegated_balance, delegation, deposit, user_balance},
+    test_helpers::{mint, setup},
+    Delegation, Identity,
+};
+use fuels::{prelude::CallParameters, signers::Signer, tx::AssetId};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn user_can_delegate() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        assert_eq!(
+            user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            0
+        );
+        assert_eq!(
+            delegated_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            asset_amount
+        );
+        assert_eq!(
+            delegation(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            Delegation {
+                amount: asset_amount,
+                delegate: Identity::Address(deployer.wallet.address()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn delegate_can_receive_from_multiple_delegators() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            deployer.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params.clone()).await;
+        deposit(&deployer.dao_voting, call_params).await;
+
+        let delegate_identity = Identity::ContractId(gov_token_id);
+        delegate(&user.dao_voting, delegate_identity.clone()).await;
+        delegate(&deployer.dao_voting, delegate_identity.clone()).await;
+
+        assert_eq!(
+            delegated_balance(&user.dao_voting, delegate_identity).await,
+            asset_amount * 2
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_already_delegated() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount / 2),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params.clone()).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        deposit(&user.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_delegating_to_self() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+        delegate(&user.dao_voting, Identity::Address(user.wallet.address())).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_delegate_with_delegation() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            deployer.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params.clone()).await;
+        deposit(&deployer.dao_voting, call_params).await;
+
+        // The deployer delegates onwards so delegating to them would form a chain
+        delegate(&deployer.dao_voting, Identity::ContractId(gov_token_id)).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_user_with_delegators() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            deployer.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params.clone()).await;
+        deposit(&deployer.dao_voting, call_params).await;
+
+        // The deployer has been delegated to so delegating onwards would form a chain
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+        delegate(&deployer.dao_voting, Identity::ContractId(gov_token_id)).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_zero_balance() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+    }
+}
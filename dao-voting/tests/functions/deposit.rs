@@ -23,7 +23,7 @@ mod success {
         )
         .await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         assert_eq!(balance(&user.dao_voting).await, 0);
 
@@ -92,7 +92,7 @@ mod revert {
 
         mint(&another_asset, asset_amount, user.wallet.address()).await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
@@ -114,7 +114,7 @@ mod revert {
         )
         .await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let call_params =
             CallParameters::new(Some(0), Some(AssetId::from(*gov_token_id)), Some(100_000));
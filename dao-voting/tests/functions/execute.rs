@@ -1,6 +1,9 @@
 use crate::utils::{
-    abi_calls::{constructor, create_proposal, deposit, execute, vote},
-    test_helpers::{mint, proposal_transaction, setup},
+    abi_calls::{
+        cancel_proposal, constructor, create_proposal, deposit, execute, execute_with_target,
+        proposal, vote,
+    },
+    test_helpers::{executable_proposal_transaction, mint, proposal_transaction, setup},
 };
 use fuels::{prelude::CallParameters, signers::Signer, tx::AssetId};
 
@@ -8,10 +11,9 @@ mod success {
     use super::*;
 
     #[tokio::test]
-    #[ignore]
     async fn user_proposal_can_execute() {
-        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        let (gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -27,13 +29,104 @@ mod success {
         );
         deposit(&user.dao_voting, call_params).await;
 
-        let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 1, proposal_transaction.clone()).await;
+        let proposal_transaction = executable_proposal_transaction(&gov_token, gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
-        execute(&user.dao_voting, 0).await;
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
+
+        assert!(proposal(&user.dao_voting, 0).await.executed);
+    }
+
+    #[tokio::test]
+    async fn user_proposal_can_execute_at_quorum() {
+        let (gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = executable_proposal_transaction(&gov_token, gov_token_id);
+        create_proposal(
+            &user.dao_voting,
+            10,
+            1,
+            asset_amount / 2,
+            proposal_transaction.clone(),
+        )
+        .await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
-        // TODO actually test execution of an arbitrary transaction
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
+    }
+
+    #[tokio::test]
+    async fn user_proposal_can_execute_with_near_max_supply() {
+        let (gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let asset_amount = u64::MAX;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = executable_proposal_transaction(&gov_token, gov_token_id);
+        create_proposal(&user.dao_voting, 99, 2, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount - 1).await;
+        vote(&user.dao_voting, false, 0, 1).await;
+
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
+    }
+
+    #[tokio::test]
+    async fn user_proposal_can_execute_after_timelock_within_grace_period() {
+        let (gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 1, 1).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = executable_proposal_transaction(&gov_token, gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // Executed on the first block after the timelock which is also the last of the grace period
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
     }
 }
 
@@ -49,10 +142,37 @@ mod revert {
 
     #[tokio::test]
     #[should_panic]
-    #[ignore]
     async fn panics_on_already_executed_proposal() {
+        let (gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = executable_proposal_transaction(&gov_token, gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
+        execute_with_target(&user.dao_voting, 0, gov_token_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    pub async fn panics_on_active_proposal() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -69,18 +189,44 @@ mod revert {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 100, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
         execute(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    pub async fn panics_on_not_enough_yes_votes() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 100, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, false, 0, asset_amount / 2).await;
+
         execute(&user.dao_voting, 0).await;
     }
 
     #[tokio::test]
     #[should_panic]
-    pub async fn panics_on_active_proposal() {
+    pub async fn panics_on_quorum_not_met() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -97,7 +243,14 @@ mod revert {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 100, proposal_transaction.clone()).await;
+        create_proposal(
+            &user.dao_voting,
+            10,
+            1,
+            asset_amount / 2 + 1,
+            proposal_transaction.clone(),
+        )
+        .await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
         execute(&user.dao_voting, 0).await;
@@ -105,9 +258,54 @@ mod revert {
 
     #[tokio::test]
     #[should_panic]
-    pub async fn panics_on_not_enough_yes_votes() {
+    pub async fn panics_on_no_votes_cast() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        execute(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    pub async fn panics_on_not_enough_yes_votes_with_near_max_supply() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let asset_amount = u64::MAX;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        // Slightly less than 100% of the votes are in favour which rounds down to 99%
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 100, 2, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount - 1).await;
+        vote(&user.dao_voting, false, 0, 1).await;
+
+        execute(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    pub async fn panics_on_cancelled_proposal() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -124,9 +322,72 @@ mod revert {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 100, proposal_transaction.clone()).await;
-        vote(&user.dao_voting, false, 0, asset_amount / 2).await;
+        create_proposal(&user.dao_voting, 10, 2, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+        cancel_proposal(&user.dao_voting, 0).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        execute(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    pub async fn panics_during_timelock() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 1, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Executed on the last block of the timelock
+        execute(&user.dao_voting, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    pub async fn panics_after_grace_period() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 1).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
 
+        // Executed on the first block after the grace period
         execute(&user.dao_voting, 0).await;
     }
 
@@ -9,7 +9,7 @@ mod success {
     #[tokio::test]
     pub async fn user_can_get_governance_token_id() {
         let (_gov_token, gov_token_id, deployer, _user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
         assert_eq!(
             governance_token_id(&deployer.dao_voting).await,
             gov_token_id
@@ -1,11 +1,15 @@
 mod balance;
+mod cancel_proposal;
 mod constructor;
 mod create_proposal;
+mod delegate;
 mod deposit;
 mod execute;
 mod governance_token_id;
 mod proposal;
 mod proposal_count;
+mod proposal_state;
+mod undelegate;
 mod unlock_votes;
 mod user_balance;
 mod user_votes;
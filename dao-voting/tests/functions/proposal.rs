@@ -11,19 +11,21 @@ mod success {
     #[tokio::test]
     pub async fn user_can_get_proposal() {
         let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
 
         assert_eq!(
             proposal(&user.dao_voting, 0).await,
             ProposalInfo {
                 author: Identity::Address(user.wallet.address()),
+                cancelled: false,
                 yes_votes: 0,
                 no_votes: 0,
                 acceptance_percentage: 10,
                 proposal_transaction,
+                quorum: 1,
                 deadline: 13,
                 executed: false,
             }
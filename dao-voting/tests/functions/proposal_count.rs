@@ -9,10 +9,10 @@ mod success {
     #[tokio::test]
     async fn use_can_get_proposal_count() {
         let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
 
         assert_eq!(proposal_count(&user.dao_voting).await, 1);
     }
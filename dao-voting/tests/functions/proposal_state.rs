@@ -0,0 +1,377 @@
+use crate::utils::{
+    abi_calls::{constructor, create_proposal, deposit, proposal_state, vote},
+    test_helpers::{mint, proposal_transaction, setup},
+    ProposalState,
+};
+use fuels::{prelude::CallParameters, signers::Signer, tx::AssetId};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_active_before_deadline() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Active()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_defeated_without_votes() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Defeated()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_defeated_without_enough_yes_votes() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, false, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Defeated()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_succeeded_after_passing() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Succeeded()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_queued_during_timelock() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 1, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // The query is made on the last block of the timelock
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Queued()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_succeeded_after_timelock() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 1, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // The query is made on the first block after the timelock
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Succeeded()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_succeeded_on_last_block_of_grace_period() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 2).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // The query is made on the last block of the grace period
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Succeeded()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_expired_after_grace_period() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 1).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // The query is made on the first block after the grace period
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Expired()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_succeeded_with_near_max_supply() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let asset_amount = u64::MAX;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 99, 2, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount - 1).await;
+        vote(&user.dao_voting, false, 0, 1).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Succeeded()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_defeated_without_enough_yes_votes_with_near_max_supply() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let asset_amount = u64::MAX;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        // Slightly less than 100% of the votes are in favour which rounds down to 99%
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 100, 2, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount - 1).await;
+        vote(&user.dao_voting, false, 0, 1).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Defeated()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_succeeded_with_max_grace_period() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 1, u64::MAX).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // Create another proposal to move past the deadline of the first one
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+
+        // The end of the grace period lies beyond u64::MAX and must not overflow
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Succeeded()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_queued_with_max_timelock() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, u64::MAX, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // The end of the timelock lies beyond u64::MAX and must not overflow
+        assert_eq!(
+            proposal_state(&user.dao_voting, 0).await,
+            ProposalState::Queued()
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_invalid_proposal_id() {
+        let (_gov_token, _gov_token_id, _deployer, user, _asset_amount) = setup().await;
+        proposal_state(&user.dao_voting, 0).await;
+    }
+}
@@ -0,0 +1,260 @@
+use crate::utils::{
+    abi_calls::{
+        constructor, create_proposal, delegate, delegated_balance, delegation, deposit, undelegate,
+        unlock_votes, user_balance, vote,
+    },
+    test_helpers::{mint, proposal_transaction, setup, setup_with_delegator},
+    Identity,
+};
+use fuels::{prelude::CallParameters, signers::Signer, tx::AssetId};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn user_can_undelegate() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+        undelegate(&user.dao_voting).await;
+
+        assert_eq!(
+            user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            asset_amount
+        );
+        assert_eq!(
+            delegated_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            0
+        );
+        assert_eq!(
+            delegation(&user.dao_voting, Identity::Address(user.wallet.address()))
+                .await
+                .amount,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn user_can_undelegate_once_delegate_unlocks_votes() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&deployer.dao_voting, 1, 1, 1, proposal_transaction.clone()).await;
+        vote(&deployer.dao_voting, true, 0, asset_amount).await;
+        unlock_votes(
+            &deployer.dao_voting,
+            0,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        undelegate(&user.dao_voting).await;
+
+        assert_eq!(
+            user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            asset_amount
+        );
+        assert_eq!(
+            user_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn user_can_undelegate_when_delegate_never_unlocks_votes() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&deployer.dao_voting, 1, 1, 1, proposal_transaction.clone()).await;
+        vote(&deployer.dao_voting, true, 0, asset_amount).await;
+
+        // The delegator unlocks the votes of the delegate on their behalf
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+        undelegate(&user.dao_voting).await;
+
+        assert_eq!(
+            user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            asset_amount
+        );
+        assert_eq!(
+            delegated_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            0
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_not_delegated() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        undelegate(&user.dao_voting).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_locked_delegated_votes() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(
+            &deployer.dao_voting,
+            10,
+            10,
+            1,
+            proposal_transaction.clone(),
+        )
+        .await;
+        vote(&deployer.dao_voting, true, 0, 1).await;
+
+        undelegate(&user.dao_voting).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_votes_locked_by_another_delegator() {
+        let (_gov_token, gov_token_id, deployer, user, delegator, asset_amount) =
+            setup_with_delegator().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        for wallet in [&user.wallet, &delegator.wallet] {
+            mint(
+                &deployer.gov_token.as_ref().unwrap(),
+                asset_amount,
+                wallet.address(),
+            )
+            .await;
+        }
+
+        for metadata in [&user, &delegator] {
+            let call_params = CallParameters::new(
+                Some(asset_amount),
+                Some(AssetId::from(*gov_token_id)),
+                Some(100_000),
+            );
+            deposit(&metadata.dao_voting, call_params).await;
+            delegate(
+                &metadata.dao_voting,
+                Identity::Address(deployer.wallet.address()),
+            )
+            .await;
+        }
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(
+            &deployer.dao_voting,
+            10,
+            10,
+            1,
+            proposal_transaction.clone(),
+        )
+        .await;
+
+        // The delegate votes with half of the pooled tokens which leaves as many unused as the
+        // user has delegated
+        vote(&deployer.dao_voting, true, 0, asset_amount).await;
+
+        // Reverts
+        undelegate(&user.dao_voting).await;
+    }
+}
@@ -1,6 +1,7 @@
 use crate::utils::{
     abi_calls::{
-        constructor, create_proposal, deposit, unlock_votes, user_balance, user_votes, vote,
+        cancel_proposal, constructor, create_proposal, deposit, unlock_votes, user_balance,
+        user_votes, vote,
     },
     test_helpers::{mint, proposal_transaction, setup},
     Identity, Votes,
@@ -13,7 +14,7 @@ mod success {
     #[tokio::test]
     async fn user_can_unlock_tokens() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -30,7 +31,7 @@ mod success {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 1, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 1, 1, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
         assert_eq!(
@@ -50,7 +51,12 @@ mod success {
             }
         );
 
-        unlock_votes(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
 
         assert_eq!(
             user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
@@ -73,7 +79,7 @@ mod success {
     #[tokio::test]
     async fn user_can_unlock_tokens_from_simultaneous_proposals() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -83,8 +89,8 @@ mod success {
         .await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 1, 3, proposal_transaction.clone()).await;
-        create_proposal(&user.dao_voting, 10, 4, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 1, 3, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 4, 1, proposal_transaction.clone()).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
@@ -124,7 +130,12 @@ mod success {
             }
         );
 
-        unlock_votes(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
         assert_eq!(
             user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
             asset_amount / 2
@@ -141,7 +152,12 @@ mod success {
                 no_votes: 0
             }
         );
-        unlock_votes(&user.dao_voting, 1).await;
+        unlock_votes(
+            &user.dao_voting,
+            1,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
         assert_eq!(
             user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
             asset_amount
@@ -163,7 +179,7 @@ mod success {
     #[tokio::test]
     async fn user_can_unlock_tokens_from_multiple_proposals() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -180,7 +196,7 @@ mod success {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 1, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 1, 1, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
 
         assert_eq!(
@@ -200,7 +216,12 @@ mod success {
             }
         );
 
-        unlock_votes(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
 
         assert_eq!(
             user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
@@ -219,7 +240,7 @@ mod success {
             }
         );
 
-        create_proposal(&user.dao_voting, 10, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 1, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 1, asset_amount / 2).await;
 
         assert_eq!(
@@ -239,7 +260,12 @@ mod success {
             }
         );
 
-        unlock_votes(&user.dao_voting, 1).await;
+        unlock_votes(
+            &user.dao_voting,
+            1,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
 
         assert_eq!(
             user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
@@ -258,6 +284,44 @@ mod success {
             }
         );
     }
+
+    #[tokio::test]
+    async fn user_can_unlock_tokens_from_cancelled_proposal() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+
+        // The deadline has not been reached but the votes are released once cancelled
+        cancel_proposal(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
+
+        assert_eq!(
+            user_balance(&user.dao_voting, Identity::Address(user.wallet.address())).await,
+            asset_amount
+        );
+    }
 }
 
 mod revert {
@@ -267,14 +331,19 @@ mod revert {
     #[should_panic]
     async fn panics_on_invalid_proposal_id() {
         let (_gov_token, _gov_token_id, _deployer, user, _asset_amount) = setup().await;
-        unlock_votes(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
     }
 
     #[tokio::test]
     #[should_panic]
     pub async fn panics_on_active_proposal() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -291,8 +360,13 @@ mod revert {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 100, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 100, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, asset_amount / 2).await;
-        unlock_votes(&user.dao_voting, 0).await;
+        unlock_votes(
+            &user.dao_voting,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
     }
 }
@@ -11,7 +11,7 @@ mod success {
     #[tokio::test]
     pub async fn user_can_check_user_balance() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -11,7 +11,7 @@ mod sucess {
     #[tokio::test]
     pub async fn user_can_check_user_votes() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -27,7 +27,7 @@ mod sucess {
         );
         deposit(&user.dao_voting, call_params).await;
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction).await;
         assert_eq!(
             user_votes(
                 &user.dao_voting,
@@ -1,5 +1,8 @@
 use crate::utils::{
-    abi_calls::{constructor, create_proposal, deposit, proposal, user_balance, user_votes, vote},
+    abi_calls::{
+        cancel_proposal, constructor, create_proposal, delegate, delegated_balance, deposit,
+        proposal, user_balance, user_votes, vote,
+    },
     test_helpers::{mint, proposal_transaction, setup},
     Identity, ProposalInfo, Votes,
 };
@@ -11,7 +14,7 @@ mod success {
     #[tokio::test]
     async fn user_can_vote() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -28,7 +31,7 @@ mod success {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
 
         vote(&user.dao_voting, true, 0, asset_amount / 4).await;
         vote(&user.dao_voting, false, 0, asset_amount / 4).await;
@@ -37,10 +40,12 @@ mod success {
             proposal(&user.dao_voting, 0).await,
             ProposalInfo {
                 author: Identity::Address(user.wallet.address()),
+                cancelled: false,
                 yes_votes: asset_amount / 4,
                 no_votes: asset_amount / 4,
                 acceptance_percentage: 10,
                 proposal_transaction,
+                quorum: 1,
                 deadline: 15,
                 executed: false,
             }
@@ -68,7 +73,7 @@ mod success {
     #[tokio::test]
     async fn user_can_vote_on_multiple_proposals() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -85,7 +90,7 @@ mod success {
         deposit(&user.dao_voting, call_params).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
 
         vote(&user.dao_voting, true, 0, asset_amount / 4).await;
         vote(&user.dao_voting, false, 0, asset_amount / 4).await;
@@ -108,7 +113,7 @@ mod success {
             }
         );
 
-        create_proposal(&user.dao_voting, 20, 20, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 20, 20, 1, proposal_transaction.clone()).await;
 
         vote(&user.dao_voting, true, 1, asset_amount / 4).await;
 
@@ -130,6 +135,101 @@ mod success {
             }
         );
     }
+
+    #[tokio::test]
+    async fn delegate_can_vote_with_delegated_balance() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            deployer.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params.clone()).await;
+        deposit(&deployer.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(
+            &deployer.dao_voting,
+            10,
+            10,
+            1,
+            proposal_transaction.clone(),
+        )
+        .await;
+        vote(&deployer.dao_voting, true, 0, asset_amount + 2).await;
+
+        // The deployer's own balance is used before the delegated balance
+        assert_eq!(
+            user_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            0
+        );
+        assert_eq!(
+            delegated_balance(
+                &user.dao_voting,
+                Identity::Address(deployer.wallet.address())
+            )
+            .await,
+            asset_amount - 2
+        );
+        assert_eq!(
+            proposal(&user.dao_voting, 0).await.yes_votes,
+            asset_amount + 2
+        );
+    }
+
+    #[tokio::test]
+    async fn user_can_vote_with_near_max_supply() {
+        let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        let asset_amount = u64::MAX;
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, asset_amount - 1).await;
+        vote(&user.dao_voting, false, 0, 1).await;
+
+        let proposal_info = proposal(&user.dao_voting, 0).await;
+        assert_eq!(proposal_info.yes_votes, asset_amount - 1);
+        assert_eq!(proposal_info.no_votes, 1);
+    }
 }
 
 mod revert {
@@ -146,10 +246,10 @@ mod revert {
     #[should_panic]
     async fn panics_on_zero_vote_amount() {
         let (_gov_token, gov_token_id, deployer, user, _asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 0, 0).await;
     }
 
@@ -157,7 +257,7 @@ mod revert {
     #[should_panic]
     async fn panics_on_expired_proposal() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         mint(
             &deployer.gov_token.as_ref().unwrap(),
@@ -167,7 +267,7 @@ mod revert {
         .await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 1, 1, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 1, 1, 1, proposal_transaction.clone()).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
@@ -182,10 +282,66 @@ mod revert {
     #[should_panic]
     async fn panics_on_vote_amount_greater_than_balance() {
         let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let proposal_transaction = proposal_transaction(gov_token_id);
-        create_proposal(&user.dao_voting, 10, 10, proposal_transaction.clone()).await;
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
         vote(&user.dao_voting, true, 10, asset_amount).await;
     }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_voting_with_delegated_tokens() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+        delegate(
+            &user.dao_voting,
+            Identity::Address(deployer.wallet.address()),
+        )
+        .await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        vote(&user.dao_voting, true, 0, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panics_on_cancelled_proposal() {
+        let (_gov_token, gov_token_id, deployer, user, asset_amount) = setup().await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
+
+        mint(
+            &deployer.gov_token.as_ref().unwrap(),
+            asset_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        let call_params = CallParameters::new(
+            Some(asset_amount),
+            Some(AssetId::from(*gov_token_id)),
+            Some(100_000),
+        );
+        deposit(&user.dao_voting, call_params).await;
+
+        let proposal_transaction = proposal_transaction(gov_token_id);
+        create_proposal(&user.dao_voting, 10, 10, 1, proposal_transaction.clone()).await;
+        cancel_proposal(&user.dao_voting, 0).await;
+        vote(&user.dao_voting, true, 0, asset_amount / 2).await;
+    }
 }
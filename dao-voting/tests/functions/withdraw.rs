@@ -19,7 +19,7 @@ mod success {
         )
         .await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
@@ -61,7 +61,7 @@ mod revert {
         )
         .await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
@@ -84,7 +84,7 @@ mod revert {
         )
         .await;
 
-        constructor(&deployer.dao_voting, gov_token_id).await;
+        constructor(&deployer.dao_voting, gov_token_id, 0, 10).await;
 
         let call_params = CallParameters::new(
             Some(asset_amount),
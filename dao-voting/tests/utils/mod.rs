@@ -16,23 +16,37 @@ pub struct Metadata {
 pub mod abi_calls {
     use super::*;
 
-    pub async fn constructor(contract: &DaoVoting, token: ContractId) -> CallResponse<()> {
-        contract.constructor(token).call().await.unwrap()
+    pub async fn constructor(
+        contract: &DaoVoting,
+        token: ContractId,
+        timelock: u64,
+        grace_period: u64,
+    ) -> CallResponse<()> {
+        contract
+            .constructor(token, timelock, grace_period)
+            .call()
+            .await
+            .unwrap()
     }
 
     pub async fn create_proposal(
         contract: &DaoVoting,
         acceptance_percentage: u64,
         deadline: u64,
+        quorum: u64,
         proposal: Proposal,
     ) -> CallResponse<()> {
         contract
-            .create_proposal(acceptance_percentage, deadline, proposal)
+            .create_proposal(acceptance_percentage, deadline, quorum, proposal)
             .call()
             .await
             .unwrap()
     }
 
+    pub async fn cancel_proposal(contract: &DaoVoting, id: u64) -> CallResponse<()> {
+        contract.cancel_proposal(id).call().await.unwrap()
+    }
+
     pub async fn deposit(contract: &DaoVoting, call_params: CallParameters) -> CallResponse<()> {
         let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
         contract
@@ -70,8 +84,37 @@ pub mod abi_calls {
         contract.execute(id).call().await.unwrap()
     }
 
-    pub async fn unlock_votes(contract: &DaoVoting, id: u64) -> CallResponse<()> {
-        contract.unlock_votes(id).call().await.unwrap()
+    pub async fn execute_with_target(
+        contract: &DaoVoting,
+        id: u64,
+        target: ContractId,
+    ) -> CallResponse<()> {
+        contract
+            .execute(id)
+            .set_contracts(&[target])
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn unlock_votes(
+        contract: &DaoVoting,
+        id: u64,
+        user_identity: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .unlock_votes(id, user_identity)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn delegate(contract: &DaoVoting, to: Identity) -> CallResponse<()> {
+        contract.delegate(to).call().await.unwrap()
+    }
+
+    pub async fn undelegate(contract: &DaoVoting) -> CallResponse<()> {
+        contract.undelegate().call().await.unwrap()
     }
 
     pub async fn balance(contract: &DaoVoting) -> u64 {
@@ -87,6 +130,24 @@ pub mod abi_calls {
             .value
     }
 
+    pub async fn delegated_balance(contract: &DaoVoting, user_identity: Identity) -> u64 {
+        contract
+            .delegated_balance(user_identity)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn delegation(contract: &DaoVoting, user_identity: Identity) -> Delegation {
+        contract
+            .delegation(user_identity)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn user_votes(contract: &DaoVoting, user_identity: Identity, id: u64) -> Votes {
         contract
             .user_votes(id, user_identity)
@@ -100,6 +161,10 @@ pub mod abi_calls {
         contract.proposal(id).call().await.unwrap().value
     }
 
+    pub async fn proposal_state(contract: &DaoVoting, id: u64) -> ProposalState {
+        contract.proposal_state(id).call().await.unwrap().value
+    }
+
     pub async fn governance_token_id(contract: &DaoVoting) -> ContractId {
         contract.governance_token_id().call().await.unwrap().value
     }
@@ -107,13 +172,30 @@ pub mod abi_calls {
     pub async fn proposal_count(contract: &DaoVoting) -> u64 {
         contract.proposal_count().call().await.unwrap().value
     }
+
+    pub async fn timelock(contract: &DaoVoting) -> u64 {
+        contract.timelock().call().await.unwrap().value
+    }
+
+    pub async fn grace_period(contract: &DaoVoting) -> u64 {
+        contract.grace_period().call().await.unwrap().value
+    }
 }
 
 pub mod test_helpers {
     use super::*;
 
     pub async fn setup() -> (GovToken, ContractId, Metadata, Metadata, u64) {
-        let num_wallets = 2;
+        let (gov_token, gov_token_id, deployer, user, _delegator, asset_amount) =
+            setup_with_delegator().await;
+
+        (gov_token, gov_token_id, deployer, user, asset_amount)
+    }
+
+    /// Same as `setup` with a third user for tests which need more than one delegator
+    pub async fn setup_with_delegator() -> (GovToken, ContractId, Metadata, Metadata, Metadata, u64)
+    {
+        let num_wallets = 3;
         let coins_per_wallet = 1;
         let amount_per_coin = 1_000_000;
         let config = WalletsConfig::new(
@@ -125,6 +207,7 @@ pub mod test_helpers {
         let mut wallets = launch_provider_and_get_wallets(config).await;
         let deployer_wallet = wallets.pop().unwrap();
         let user_wallet = wallets.pop().unwrap();
+        let delegator_wallet = wallets.pop().unwrap();
 
         let dao_voting_id = Contract::deploy(
             "./out/debug/dao-voting.bin",
@@ -159,9 +242,22 @@ pub mod test_helpers {
             wallet: user_wallet,
         };
 
+        let delegator = Metadata {
+            dao_voting: DaoVoting::new(dao_voting_id.to_string(), delegator_wallet.clone()),
+            gov_token: None,
+            wallet: delegator_wallet,
+        };
+
         let asset_amount: u64 = 10;
 
-        (gov_token, gov_token_id, deployer, user, asset_amount)
+        (
+            gov_token,
+            gov_token_id,
+            deployer,
+            user,
+            delegator,
+            asset_amount,
+        )
     }
 
     pub async fn mint(contract: &GovToken, amount: u64, address: Address) -> bool {
@@ -190,4 +286,13 @@ pub mod test_helpers {
 
         proposal
     }
+
+    /// A proposal which calls `proposal_target` on the governance token when it is executed
+    pub fn executable_proposal_transaction(gov_token: &GovToken, asset_id: ContractId) -> Proposal {
+        let mut proposal = proposal_transaction(asset_id);
+        proposal.call_data.function_selector =
+            u64::from_be_bytes(gov_token.proposal_target().contract_call.encoded_selector);
+
+        proposal
+    }
 }
@@ -0,0 +1,262 @@
+use crate::{CampaignInfo, CampaignStatus, Error, Identity, RewardTier};
+use fuels::{
+    core::{abi_decoder::ABIDecoder, ParamType, Parameterize, Token, Tokenizable},
+    tx::{ContractId, Receipt},
+};
+
+/// A type which can be a field of an event logged by the fundraiser
+trait Field: Tokenizable {
+    /// Returns the layout of the field within the log
+    fn param_type() -> ParamType;
+}
+
+impl Field for bool {
+    fn param_type() -> ParamType {
+        ParamType::Bool
+    }
+}
+
+impl Field for u64 {
+    fn param_type() -> ParamType {
+        ParamType::U64
+    }
+}
+
+impl Field for ContractId {
+    fn param_type() -> ParamType {
+        ParamType::Struct(vec![ParamType::B256])
+    }
+}
+
+impl Field for CampaignInfo {
+    fn param_type() -> ParamType {
+        ParamType::Struct(CampaignInfo::param_types())
+    }
+}
+
+impl Field for CampaignStatus {
+    fn param_type() -> ParamType {
+        ParamType::Enum(CampaignStatus::param_types())
+    }
+}
+
+impl Field for Identity {
+    fn param_type() -> ParamType {
+        ParamType::Enum(Identity::param_types())
+    }
+}
+
+impl Field for RewardTier {
+    fn param_type() -> ParamType {
+        ParamType::Struct(RewardTier::param_types())
+    }
+}
+
+/// Declares the `Event` enum alongside the layout of the `FundraiserEvent` enum of the contract
+///
+/// The variants and their fields must be declared in the same order as in `src/events.sw` since
+/// that order determines the discriminant of each variant and the position of each field
+macro_rules! events {
+    ($($(#[$doc:meta])* $variant:ident { $($field:ident: $type:ty),* $(,)? }),* $(,)?) => {
+        /// An event logged by the fundraiser, named after its event struct without the `Event`
+        /// suffix
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Event {
+            $($(#[$doc])* $variant { $($field: $type),* }),*
+        }
+
+        impl Event {
+            /// Returns the name of the event struct without the `Event` suffix
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Event::$variant { .. } => stringify!($variant)),*
+                }
+            }
+
+            /// Returns the layout of the `FundraiserEvent` enum in which every event is logged
+            fn param_type() -> ParamType {
+                ParamType::Enum(vec![
+                    $(ParamType::Struct(vec![$(<$type as Field>::param_type()),*])),*
+                ])
+            }
+
+            /// Converts the decoded `FundraiserEvent` into the event of its variant
+            fn from_token(token: Token) -> Result<Self, Error> {
+                let (discriminant, token) = match token {
+                    Token::Enum(selector) => *selector,
+                    _ => return Err(Error::Decode("FundraiserEvent")),
+                };
+
+                let mut discriminants = 0u8..;
+                $(
+                    if discriminants.next() == Some(discriminant) {
+                        let mut fields = match token {
+                            Token::Struct(fields) => fields.into_iter(),
+                            _ => return Err(Error::Decode(stringify!($variant))),
+                        };
+
+                        return Ok(Event::$variant {
+                            $($field: field(&mut fields, stringify!($variant))?),*
+                        });
+                    }
+                )*
+
+                Err(Error::Decode("FundraiserEvent"))
+            }
+        }
+    };
+}
+
+events! {
+    AbandonedMilestones { id: u64, tranche: u64 },
+    AddedRewardTier { id: u64, reward_tier: RewardTier, tier: u64 },
+    AddedStretchGoal { goal: u64, id: u64, target: u64 },
+    Allowlisted { id: u64, user: Identity },
+    CancelledCampaign { id: u64 },
+    Claimed { id: u64 },
+    ClaimedReward { id: u64, tier: u64, user: Identity },
+    CreatedCampaign { author: Identity, campaign_info: CampaignInfo, id: u64 },
+    ExtendedDeadline { deadline: u64, id: u64 },
+    Finalized { id: u64, status: CampaignStatus },
+    Initialized { author: Identity, fee: u64, fee_recipient: Identity },
+    Matched { amount: u64, id: u64, user: Identity },
+    Pledged { amount: u64, id: u64, user: Identity },
+    /// Logged after the `Pledged` event of the `user` on whose behalf the pledge was made
+    PledgedFor { amount: u64, id: u64, sender: Identity, user: Identity },
+    ProposedTranche { id: u64, tranche: u64, vote_deadline: u64 },
+    PulledSubscription { amount: u64, id: u64, subscription_id: u64 },
+    Reclaimed { amount: u64, id: u64, user: Identity },
+    ReachedStretchGoal { goal: u64, id: u64, target: u64 },
+    RecordedPrice { id: u64, price: u64 },
+    Refunded { amount: u64, id: u64, user: Identity },
+    RejectedTranche { id: u64, tranche: u64 },
+    ReleasedTranche { amount: u64, id: u64, tranche: u64 },
+    RemovedFromAllowlist { id: u64, user: Identity },
+    RemovedRewardSelection { id: u64, tier: u64, user: Identity },
+    RequestedExtension { extension: u64, id: u64 },
+    SelectedRewardTier { id: u64, tier: u64, user: Identity },
+    SetMilestones { id: u64, tranches: u64, voting_period: u64 },
+    SetPrivate { id: u64, private: bool },
+    SetQuoteTarget { id: u64, oracle: ContractId, target: u64 },
+    Sponsored { amount: u64, id: u64, sponsor: Identity },
+    SponsorRefunded { amount: u64, id: u64, sponsor: Identity },
+    Subscribed {
+        amount: u64,
+        balance: u64,
+        id: u64,
+        period: u64,
+        subscription_id: u64,
+        user: Identity,
+    },
+    TransferredPledge { amount: u64, from: Identity, id: u64, to: Identity },
+    Unmatched { amount: u64, id: u64, user: Identity },
+    Unpledged { amount: u64, id: u64, user: Identity },
+    UnreachedStretchGoal { goal: u64, id: u64, target: u64 },
+    Unsubscribed { amount: u64, subscription_id: u64, user: Identity },
+    UpdatedCampaign { campaign_info: CampaignInfo, id: u64 },
+    VotedExtension { id: u64, user: Identity, weight: u64 },
+    VotedTranche { approve: bool, id: u64, tranche: u64, user: Identity, weight: u64 },
+    WithdrawnFees { amount: u64, asset: ContractId, recipient: Identity },
+}
+
+impl Event {
+    /// Returns the campaign that the event belongs to or `None` if the event does not belong to a
+    /// single campaign
+    pub fn campaign(&self) -> Option<u64> {
+        match self {
+            Event::Initialized { .. }
+            | Event::Unsubscribed { .. }
+            | Event::WithdrawnFees { .. } => None,
+            Event::AbandonedMilestones { id, .. }
+            | Event::AddedRewardTier { id, .. }
+            | Event::AddedStretchGoal { id, .. }
+            | Event::Allowlisted { id, .. }
+            | Event::CancelledCampaign { id }
+            | Event::Claimed { id }
+            | Event::ClaimedReward { id, .. }
+            | Event::CreatedCampaign { id, .. }
+            | Event::ExtendedDeadline { id, .. }
+            | Event::Finalized { id, .. }
+            | Event::Matched { id, .. }
+            | Event::Pledged { id, .. }
+            | Event::PledgedFor { id, .. }
+            | Event::ProposedTranche { id, .. }
+            | Event::PulledSubscription { id, .. }
+            | Event::Reclaimed { id, .. }
+            | Event::ReachedStretchGoal { id, .. }
+            | Event::RecordedPrice { id, .. }
+            | Event::Refunded { id, .. }
+            | Event::RejectedTranche { id, .. }
+            | Event::ReleasedTranche { id, .. }
+            | Event::RemovedFromAllowlist { id, .. }
+            | Event::RemovedRewardSelection { id, .. }
+            | Event::RequestedExtension { id, .. }
+            | Event::SelectedRewardTier { id, .. }
+            | Event::SetMilestones { id, .. }
+            | Event::SetPrivate { id, .. }
+            | Event::SetQuoteTarget { id, .. }
+            | Event::Sponsored { id, .. }
+            | Event::SponsorRefunded { id, .. }
+            | Event::Subscribed { id, .. }
+            | Event::TransferredPledge { id, .. }
+            | Event::Unmatched { id, .. }
+            | Event::Unpledged { id, .. }
+            | Event::UnreachedStretchGoal { id, .. }
+            | Event::UpdatedCampaign { id, .. }
+            | Event::VotedExtension { id, .. }
+            | Event::VotedTranche { id, .. } => Some(*id),
+        }
+    }
+}
+
+/// Decodes the events that the fundraiser has logged in the receipts of a transaction
+///
+/// Every log of the fundraiser is a `FundraiserEvent` whose discriminant identifies the event
+/// therefore the events are returned in the order in which they have been logged.
+///
+/// # Arguments
+///
+/// * `contract_id` - The ID of the fundraiser contract
+/// * `receipts` - The receipts of a transaction which has been included in a block
+///
+/// # Errors
+///
+/// * When a log of the fundraiser cannot be decoded into a `FundraiserEvent`
+pub fn decode(contract_id: ContractId, receipts: &[Receipt]) -> Result<Vec<Event>, Error> {
+    // A reverted transaction has not changed the state of the contract
+    if receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Revert { .. } | Receipt::Panic { .. }))
+    {
+        return Ok(vec![]);
+    }
+
+    let param_type = Event::param_type();
+
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::LogData { id, data, .. } if *id == contract_id => Some(data),
+            _ => None,
+        })
+        .map(|data| {
+            let mut tokens = ABIDecoder::new()
+                .decode(&[param_type.clone()], data)
+                .map_err(|_| Error::Decode("FundraiserEvent"))?;
+
+            match tokens.pop() {
+                Some(token) => Event::from_token(token),
+                None => Err(Error::Decode("FundraiserEvent")),
+            }
+        })
+        .collect()
+}
+
+/// Converts the next decoded field of an event into its type
+fn field<T: Field>(
+    fields: &mut impl Iterator<Item = Token>,
+    event: &'static str,
+) -> Result<T, Error> {
+    let token = fields.next().ok_or(Error::Decode(event))?;
+    T::from_token(token).map_err(|_| Error::Decode(event))
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Errors that can occur while indexing the events of the fundraiser
+#[derive(Debug)]
+pub enum Error {
+    /// A log of the fundraiser did not have the layout of the event emitted by the function
+    Decode(&'static str),
+
+    /// The database could not be read from or written to
+    Sqlite(rusqlite::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Decode(event) => write!(f, "unable to decode {}", event),
+            Error::Sqlite(error) => write!(f, "database error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::Sqlite(error)
+    }
+}
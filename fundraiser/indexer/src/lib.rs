@@ -0,0 +1,71 @@
+//! Indexes the events logged by the fundraiser contract into an embedded SQLite database so that
+//! an interface can display leaderboards and activity without iterating over contract storage.
+//!
+//! The receipts of each transaction sent to the fundraiser are passed to [`Indexer::index`]
+//! alongside the timestamp of the block that included the transaction.
+
+mod decode;
+mod error;
+mod store;
+
+pub use decode::{decode, Event};
+pub use error::Error;
+pub use store::{Activity, Backer, DailyVolume, Store};
+
+use fuels::tx::{ContractId, Receipt};
+use fuels_abigen_macro::abigen;
+
+abigen!(Fundraiser, "../out/debug/fundraiser-abi.json");
+
+/// Number of seconds in a day used to group pledges into daily volumes
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Decodes the events of a single fundraiser contract and persists them in a [`Store`]
+pub struct Indexer {
+    /// The fundraiser contract whose events are indexed
+    contract_id: ContractId,
+
+    /// The database in which the events are persisted
+    store: Store,
+}
+
+impl Indexer {
+    /// Creates an indexer for the fundraiser at `contract_id` which persists events in the `store`
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the fundraiser contract
+    /// * `store` - The database in which the events are persisted
+    pub fn new(contract_id: ContractId, store: Store) -> Self {
+        Self { contract_id, store }
+    }
+
+    /// Decodes the events in the receipts of a transaction and persists them
+    ///
+    /// Transactions which have reverted do not change the state of the contract and are ignored.
+    /// Returns the events that have been persisted.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipts` - The receipts of a transaction which has been included in a block
+    /// * `timestamp` - The time in seconds at which the block was produced
+    ///
+    /// # Errors
+    ///
+    /// * When a log of the fundraiser cannot be decoded into its event
+    /// * When the database cannot be written to
+    pub fn index(&mut self, receipts: &[Receipt], timestamp: u64) -> Result<Vec<Event>, Error> {
+        let events = decode(self.contract_id, receipts)?;
+
+        for event in &events {
+            self.store.insert(event, timestamp)?;
+        }
+
+        Ok(events)
+    }
+
+    /// Returns the database in which the events are persisted in order to query it
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{CampaignStatus, Error, Event, Identity, SECONDS_PER_DAY};
+use fuels::tx::{Address, ContractId};
+use rusqlite::{params, Connection};
+use std::path::Path;
+
+/// Status of a campaign which has not been cancelled, claimed or finalized
+const ACTIVE: i64 = 0;
+
+/// Status of a campaign which has been cancelled by its author
+const CANCELLED: i64 = 1;
+
+/// Status of a campaign which has been claimed by its author
+const CLAIMED: i64 = 2;
+
+/// Status of a campaign which has been finalized without reaching its target
+const FAILED: i64 = 3;
+
+/// Status of a campaign which has been finalized having reached its target
+const SUCCEEDED: i64 = 4;
+
+/// Discriminant of an `Identity::Address`
+const ADDRESS: i64 = 0;
+
+/// Discriminant of an `Identity::ContractId`
+const CONTRACT_ID: i64 = 1;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS campaigns (
+        id INTEGER PRIMARY KEY,
+        asset BLOB NOT NULL,
+        author_kind INTEGER NOT NULL,
+        author BLOB NOT NULL,
+        category INTEGER NOT NULL,
+        deadline INTEGER NOT NULL,
+        status INTEGER NOT NULL,
+        target_amount INTEGER NOT NULL
+    );
+
+    -- Pledges are positive and unpledges are negative so that the sum is the current pledge
+    -- Transfers and reclaims only move the pledge therefore they are excluded from the volume
+    CREATE TABLE IF NOT EXISTS pledges (
+        campaign INTEGER NOT NULL,
+        user_kind INTEGER NOT NULL,
+        user BLOB NOT NULL,
+        amount INTEGER NOT NULL,
+        timestamp INTEGER NOT NULL,
+        volume INTEGER NOT NULL DEFAULT 1
+    );
+
+    CREATE INDEX IF NOT EXISTS pledges_by_campaign ON pledges (campaign);
+
+    -- Every event in the order in which it has been indexed
+    -- Events which do not belong to a single campaign have a NULL campaign
+    CREATE TABLE IF NOT EXISTS events (
+        campaign INTEGER,
+        name TEXT NOT NULL,
+        timestamp INTEGER NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS events_by_campaign ON events (campaign);
+";
+
+/// An event of a campaign and the time at which it was included in a block
+#[derive(Clone, Debug, PartialEq)]
+pub struct Activity {
+    /// The name of the event struct without the `Event` suffix
+    pub name: String,
+    pub timestamp: u64,
+}
+
+/// A user and the amount that they currently have pledged to a campaign
+#[derive(Clone, Debug, PartialEq)]
+pub struct Backer {
+    pub amount: u64,
+    pub user: Identity,
+}
+
+/// The amount pledged to and unpledged from a campaign during a day
+#[derive(Clone, Debug, PartialEq)]
+pub struct DailyVolume {
+    /// The number of days since the unix epoch
+    pub day: u64,
+    pub pledged: u64,
+    pub unpledged: u64,
+}
+
+/// An SQLite database containing the indexed events
+///
+/// Amounts are stored as signed 64 bit integers and therefore amounts above `i64::MAX` are not
+/// supported
+pub struct Store {
+    connection: Connection,
+}
+
+impl Store {
+    /// Opens, or creates, the database at the `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Creates a database which only exists in memory for as long as the store
+    pub fn in_memory() -> Result<Self, Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    /// Persists an event which was included in a block produced at the `timestamp`
+    ///
+    /// Every event is recorded as activity and the events which change a campaign or a pledge
+    /// also update the corresponding tables
+    pub fn insert(&self, event: &Event, timestamp: u64) -> Result<(), Error> {
+        self.connection.execute(
+            "INSERT INTO events (campaign, name, timestamp) VALUES (?1, ?2, ?3)",
+            params![
+                event.campaign().map(|id| id as i64),
+                event.name(),
+                timestamp as i64
+            ],
+        )?;
+
+        match event {
+            Event::CancelledCampaign { id } => self.set_status(*id, CANCELLED),
+            Event::Claimed { id } => self.set_status(*id, CLAIMED),
+            Event::CreatedCampaign {
+                author,
+                campaign_info,
+                id,
+            } => {
+                let (author_kind, author) = split_identity(author);
+                self.connection.execute(
+                    "INSERT OR REPLACE INTO campaigns
+                        (id, asset, author_kind, author, category, deadline, status, target_amount)
+                        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        *id as i64,
+                        &campaign_info.asset[..],
+                        author_kind,
+                        &author[..],
+                        campaign_info.category as i64,
+                        campaign_info.deadline as i64,
+                        ACTIVE,
+                        campaign_info.target_amount as i64
+                    ],
+                )?;
+                Ok(())
+            }
+            Event::ExtendedDeadline { deadline, id } => {
+                self.connection.execute(
+                    "UPDATE campaigns SET deadline = ?2 WHERE id = ?1",
+                    params![*id as i64, *deadline as i64],
+                )?;
+                Ok(())
+            }
+            Event::Finalized { id, status } => {
+                let status = match status {
+                    CampaignStatus::Active() => ACTIVE,
+                    CampaignStatus::Cancelled() => CANCELLED,
+                    CampaignStatus::Claimed() => CLAIMED,
+                    CampaignStatus::Failed() => FAILED,
+                    CampaignStatus::Succeeded() => SUCCEEDED,
+                };
+                self.set_status(*id, status)
+            }
+            Event::Pledged { amount, id, user } => {
+                self.insert_pledge(*id, user, *amount as i64, timestamp, true)
+            }
+            Event::Reclaimed { id, user, .. } => self.clear_pledge(*id, user, timestamp),
+            Event::Refunded { amount, id, user } | Event::Unpledged { amount, id, user } => {
+                self.insert_pledge(*id, user, -(*amount as i64), timestamp, true)
+            }
+            Event::TransferredPledge {
+                amount,
+                from,
+                id,
+                to,
+            } => {
+                self.insert_pledge(*id, from, -(*amount as i64), timestamp, false)?;
+                self.insert_pledge(*id, to, *amount as i64, timestamp, false)
+            }
+            Event::UpdatedCampaign { campaign_info, id } => {
+                self.connection.execute(
+                    "UPDATE campaigns SET deadline = ?2, target_amount = ?3 WHERE id = ?1",
+                    params![
+                        *id as i64,
+                        campaign_info.deadline as i64,
+                        campaign_info.target_amount as i64
+                    ],
+                )?;
+                Ok(())
+            }
+            // The pledge of a `PledgedFor` event has already been persisted through the `Pledged`
+            // event logged before it and the remaining events are only recorded as activity
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the IDs of the campaigns which have not been cancelled, claimed or finalized and
+    /// whose deadline, including any extension, is ahead of the `height` in ascending order
+    pub fn active_campaigns(&self, height: u64) -> Result<Vec<u64>, Error> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT id FROM campaigns WHERE status = ?1 AND ?2 < deadline ORDER BY id")?;
+        let ids = statement
+            .query_map(params![ACTIVE, height as i64], |row| row.get::<_, i64>(0))?
+            .map(|id| id.map(|id| id as u64))
+            .collect::<Result<_, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Returns the events of a campaign in the order in which they have been indexed
+    pub fn activity(&self, id: u64) -> Result<Vec<Activity>, Error> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT name, timestamp FROM events WHERE campaign = ?1 ORDER BY rowid")?;
+        let activity = statement
+            .query_map(params![id as i64], |row| {
+                Ok(Activity {
+                    name: row.get(0)?,
+                    timestamp: row.get::<_, i64>(1)? as u64,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(activity)
+    }
+
+    /// Returns up to `limit` backers of a campaign ordered by the amount that they currently have
+    /// pledged, largest first
+    ///
+    /// Backers that have unpledged their entire pledge are excluded
+    pub fn top_backers(&self, id: u64, limit: u64) -> Result<Vec<Backer>, Error> {
+        let mut statement = self.connection.prepare(
+            "SELECT user_kind, user, SUM(amount) AS total FROM pledges
+                WHERE campaign = ?1
+                GROUP BY user_kind, user
+                HAVING 0 < total
+                ORDER BY total DESC, user ASC
+                LIMIT ?2",
+        )?;
+        let backers = statement
+            .query_map(params![id as i64, limit as i64], |row| {
+                Ok(Backer {
+                    amount: row.get::<_, i64>(2)? as u64,
+                    user: join_identity(row.get(0)?, row.get(1)?),
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(backers)
+    }
+
+    /// Returns the amount pledged to and unpledged from a campaign on each day with any activity in
+    /// ascending order of day
+    pub fn daily_volume(&self, id: u64) -> Result<Vec<DailyVolume>, Error> {
+        let mut statement = self.connection.prepare(
+            "SELECT timestamp / ?2 AS day,
+                    SUM(CASE WHEN 0 < amount THEN amount ELSE 0 END),
+                    SUM(CASE WHEN amount < 0 THEN -amount ELSE 0 END)
+                FROM pledges
+                WHERE campaign = ?1 AND volume = 1
+                GROUP BY day
+                ORDER BY day",
+        )?;
+        let volumes = statement
+            .query_map(params![id as i64, SECONDS_PER_DAY as i64], |row| {
+                Ok(DailyVolume {
+                    day: row.get::<_, i64>(0)? as u64,
+                    pledged: row.get::<_, i64>(1)? as u64,
+                    unpledged: row.get::<_, i64>(2)? as u64,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(volumes)
+    }
+
+    fn set_status(&self, id: u64, status: i64) -> Result<(), Error> {
+        self.connection.execute(
+            "UPDATE campaigns SET status = ?2 WHERE id = ?1",
+            params![id as i64, status],
+        )?;
+        Ok(())
+    }
+
+    fn insert_pledge(
+        &self,
+        id: u64,
+        user: &Identity,
+        amount: i64,
+        timestamp: u64,
+        volume: bool,
+    ) -> Result<(), Error> {
+        let (user_kind, user) = split_identity(user);
+        self.connection.execute(
+            "INSERT INTO pledges (campaign, user_kind, user, amount, timestamp, volume)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                id as i64,
+                user_kind,
+                &user[..],
+                amount,
+                timestamp as i64,
+                volume
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Removes the current pledge of a user who has reclaimed their share of a campaign
+    ///
+    /// The reclaimed amount is a share of the unreleased remainder rather than the pledge itself
+    /// therefore the pledge is offset by its current sum. A sponsor without a pledge is skipped.
+    fn clear_pledge(&self, id: u64, user: &Identity, timestamp: u64) -> Result<(), Error> {
+        let (user_kind, user) = split_identity(user);
+        self.connection.execute(
+            "INSERT INTO pledges (campaign, user_kind, user, amount, timestamp, volume)
+                SELECT campaign, user_kind, user, -SUM(amount), ?4, 0 FROM pledges
+                    WHERE campaign = ?1 AND user_kind = ?2 AND user = ?3
+                    GROUP BY campaign, user_kind, user
+                    HAVING SUM(amount) != 0",
+            params![id as i64, user_kind, &user[..], timestamp as i64],
+        )?;
+        Ok(())
+    }
+}
+
+/// Splits an `Identity` into its discriminant and its bytes so that it can be stored
+fn split_identity(identity: &Identity) -> (i64, [u8; 32]) {
+    match identity {
+        Identity::Address(address) => (ADDRESS, **address),
+        Identity::ContractId(contract_id) => (CONTRACT_ID, **contract_id),
+    }
+}
+
+/// Rebuilds an `Identity` from the discriminant and bytes that have been stored
+fn join_identity(kind: i64, bytes: Vec<u8>) -> Identity {
+    let mut b256 = [0u8; 32];
+    b256.copy_from_slice(&bytes);
+
+    if kind == CONTRACT_ID {
+        Identity::ContractId(ContractId::new(b256))
+    } else {
+        Identity::Address(Address::new(b256))
+    }
+}
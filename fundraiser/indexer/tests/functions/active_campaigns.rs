@@ -0,0 +1,129 @@
+use crate::utils::{
+    abi_calls::{
+        cancel_campaign, claim_pledges, create_campaign, finalize, pledge, request_extension,
+        update_campaign, vote_extension,
+    },
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+use fundraiser_indexer::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn excludes_cancelled_and_claimed_campaigns() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+        let deadline = 8;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(
+                &author.contract,
+                &asset.id,
+                beneficiary.clone(),
+                deadline,
+                512,
+            )
+            .await,
+            pledge(&user.contract, 1, &asset, 512).await,
+            create_campaign(&author.contract, &asset.id, beneficiary.clone(), 100, 512).await,
+            create_campaign(&author.contract, &asset.id, beneficiary, 100, 512).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(vec![1, 2, 3], indexer.store().active_campaigns(7).unwrap());
+
+        let response = cancel_campaign(&author.contract, 2).await;
+        indexer.index(&response.receipts, 0).unwrap();
+
+        let response = claim_pledges(&author.contract, 1).await;
+        indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(vec![3], indexer.store().active_campaigns(10).unwrap());
+    }
+
+    #[tokio::test]
+    async fn excludes_campaigns_which_have_reached_their_deadline() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+
+        let response =
+            create_campaign(&author.contract, &asset.id, beneficiary.clone(), 6, 512).await;
+        indexer.index(&response.receipts, 0).unwrap();
+
+        let response = create_campaign(&author.contract, &asset.id, beneficiary, 100, 512).await;
+        indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(vec![1, 2], indexer.store().active_campaigns(5).unwrap());
+        assert_eq!(vec![2], indexer.store().active_campaigns(6).unwrap());
+    }
+
+    #[tokio::test]
+    async fn excludes_finalized_campaigns() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+        let deadline = 6;
+
+        let responses = vec![
+            create_campaign(
+                &author.contract,
+                &asset.id,
+                beneficiary.clone(),
+                deadline,
+                512,
+            )
+            .await,
+            create_campaign(&author.contract, &asset.id, beneficiary, 100, 512).await,
+            finalize(&author.contract, 1).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(vec![2], indexer.store().active_campaigns(0).unwrap());
+    }
+
+    #[tokio::test]
+    async fn uses_updated_deadline() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+
+        let responses = vec![
+            create_campaign(&author.contract, &asset.id, beneficiary.clone(), 6, 512).await,
+            update_campaign(&author.contract, 1, beneficiary, 100, 512).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(vec![1], indexer.store().active_campaigns(50).unwrap());
+    }
+
+    #[tokio::test]
+    async fn uses_extended_deadline() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+        let deadline = 10;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(&author.contract, &asset.id, beneficiary, deadline, 1024).await,
+            pledge(&user.contract, 1, &asset, 512).await,
+            request_extension(&author.contract, 1, 10).await,
+            vote_extension(&user.contract, 1).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(vec![1], indexer.store().active_campaigns(15).unwrap());
+        assert!(indexer.store().active_campaigns(20).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::utils::{
+    abi_calls::{create_campaign, pledge, set_private},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+use fundraiser_indexer::{Activity, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_events_of_campaign_in_order() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let beneficiary = Identity::Address(author.wallet.address());
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(&author.contract, &asset.id, beneficiary.clone(), 100, 512).await,
+            create_campaign(&author.contract, &asset.id, beneficiary, 100, 512).await,
+            pledge(&user.contract, 1, &asset, 256).await,
+            set_private(&author.contract, 1, true).await,
+        ];
+        for (timestamp, response) in responses.into_iter().enumerate() {
+            indexer.index(&response.receipts, timestamp as u64).unwrap();
+        }
+
+        assert_eq!(
+            vec![
+                Activity {
+                    name: "CreatedCampaign".to_string(),
+                    timestamp: 0,
+                },
+                Activity {
+                    name: "Pledged".to_string(),
+                    timestamp: 2,
+                },
+                Activity {
+                    name: "SetPrivate".to_string(),
+                    timestamp: 3,
+                },
+            ],
+            indexer.store().activity(1).unwrap()
+        );
+    }
+}
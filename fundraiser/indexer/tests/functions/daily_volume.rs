@@ -0,0 +1,55 @@
+use crate::utils::{
+    abi_calls::{create_campaign, pledge, unpledge},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+use fundraiser_indexer::{DailyVolume, Identity, SECONDS_PER_DAY};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn groups_pledges_by_day() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+
+        let response = create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            512,
+        )
+        .await;
+        indexer.index(&response.receipts, 0).unwrap();
+
+        let response = pledge(&user.contract, 1, &asset, 256).await;
+        indexer.index(&response.receipts, 10).unwrap();
+
+        let response = unpledge(&user.contract, 1, 64).await;
+        indexer.index(&response.receipts, 20).unwrap();
+
+        let response = pledge(&user.contract, 1, &asset, 128).await;
+        indexer
+            .index(&response.receipts, SECONDS_PER_DAY * 2)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                DailyVolume {
+                    day: 0,
+                    pledged: 256,
+                    unpledged: 64,
+                },
+                DailyVolume {
+                    day: 2,
+                    pledged: 128,
+                    unpledged: 0,
+                },
+            ],
+            indexer.store().daily_volume(1).unwrap()
+        );
+    }
+}
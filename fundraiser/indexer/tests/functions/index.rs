@@ -0,0 +1,283 @@
+use crate::utils::{
+    abi_calls::{
+        add_reward_tier, cancel_campaign, claim_pledges, create_campaign, pledge, pledge_for,
+        refund_batch, select_reward_tier, set_private, transfer_pledge, unpledge,
+    },
+    test_helpers::{advance_to, deploy_nft, mint, setup},
+};
+use fuels::signers::Signer;
+use fundraiser_indexer::{Event, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn indexes_created_campaign() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+        let author_identity = Identity::Address(author.wallet.address());
+
+        let response = create_campaign(
+            &author.contract,
+            &asset.id,
+            author_identity.clone(),
+            100,
+            512,
+        )
+        .await;
+        let mut events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(1, events.len());
+        match events.pop().unwrap() {
+            Event::CreatedCampaign {
+                author,
+                campaign_info,
+                id,
+            } => {
+                assert_eq!(author_identity, author);
+                assert_eq!(asset.id, campaign_info.asset);
+                assert_eq!(1, campaign_info.category);
+                assert_eq!(100, campaign_info.deadline);
+                assert_eq!(512, campaign_info.target_amount);
+                assert_eq!(1, id);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn indexes_pledge_and_unpledge() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let user_identity = Identity::Address(user.wallet.address());
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            512,
+        )
+        .await;
+
+        let response = pledge(&user.contract, 1, &asset, 512).await;
+        let pledged = indexer.index(&response.receipts, 0).unwrap();
+
+        let response = unpledge(&user.contract, 1, 256).await;
+        let unpledged = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(
+            vec![Event::Pledged {
+                amount: 512,
+                id: 1,
+                user: user_identity.clone(),
+            }],
+            pledged
+        );
+        assert_eq!(
+            vec![Event::Unpledged {
+                amount: 256,
+                id: 1,
+                user: user_identity,
+            }],
+            unpledged
+        );
+    }
+
+    #[tokio::test]
+    async fn indexes_pledge_for_and_transfer_pledge() {
+        let (author, user, user2, asset, mut indexer) = setup().await;
+        let author_identity = Identity::Address(author.wallet.address());
+        let user_identity = Identity::Address(user.wallet.address());
+        let user2_identity = Identity::Address(user2.wallet.address());
+
+        mint(&asset.contract, 512, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            512,
+        )
+        .await;
+
+        // The author gifts a pledge which belongs to the user
+        let response = pledge_for(&author.contract, 1, user_identity.clone(), &asset, 512).await;
+        let pledged = indexer.index(&response.receipts, 0).unwrap();
+
+        let response = transfer_pledge(&user.contract, 1, user2_identity.clone(), 128).await;
+        let transferred = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(
+            vec![
+                Event::Pledged {
+                    amount: 512,
+                    id: 1,
+                    user: user_identity.clone(),
+                },
+                Event::PledgedFor {
+                    amount: 512,
+                    id: 1,
+                    sender: author_identity,
+                    user: user_identity.clone(),
+                },
+            ],
+            pledged
+        );
+        assert_eq!(
+            vec![Event::TransferredPledge {
+                amount: 128,
+                from: user_identity,
+                id: 1,
+                to: user2_identity,
+            }],
+            transferred
+        );
+    }
+
+    #[tokio::test]
+    async fn indexes_each_refund_in_batch() {
+        let (author, user, user2, asset, mut indexer) = setup().await;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+        mint(&asset.contract, 256, user2.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            1024,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 512).await;
+        pledge(&user2.contract, 1, &asset, 256).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        let response = refund_batch(&author.contract, 1, 1, 2).await;
+        let events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(
+            vec![
+                Event::Refunded {
+                    amount: 512,
+                    id: 1,
+                    user: Identity::Address(user.wallet.address()),
+                },
+                Event::Refunded {
+                    amount: 256,
+                    id: 1,
+                    user: Identity::Address(user2.wallet.address()),
+                },
+            ],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn indexes_cancelled_campaign() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            512,
+        )
+        .await;
+
+        let response = cancel_campaign(&author.contract, 1).await;
+        let events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(vec![Event::CancelledCampaign { id: 1 }], events);
+    }
+
+    #[tokio::test]
+    async fn indexes_claimed_campaign() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let deadline = 7;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            deadline,
+            512,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 512).await;
+
+        let response = claim_pledges(&author.contract, 1).await;
+        let events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(vec![Event::Claimed { id: 1 }], events);
+    }
+
+    #[tokio::test]
+    async fn indexes_events_which_do_not_change_pledges() {
+        let (author, _, _, asset, mut indexer) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            100,
+            512,
+        )
+        .await;
+
+        let response = set_private(&author.contract, 1, true).await;
+        let events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(
+            vec![Event::SetPrivate {
+                id: 1,
+                private: true,
+            }],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn indexes_refund_after_removed_reward_selection() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+        let user_identity = Identity::Address(user.wallet.address());
+        let deadline = 12;
+        let nft = deploy_nft(&author.wallet, &author.contract_id).await;
+
+        mint(&asset.contract, 512, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &asset.id,
+            Identity::Address(author.wallet.address()),
+            deadline,
+            1024,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 512, &nft, 1).await;
+        pledge(&user.contract, 1, &asset, 512).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+        advance_to(&author.wallet, deadline).await;
+
+        // The removed selection and the refund have the same fields but are both indexed
+        let response = refund_batch(&author.contract, 1, 1, 1).await;
+        let events = indexer.index(&response.receipts, 0).unwrap();
+
+        assert_eq!(
+            vec![
+                Event::RemovedRewardSelection {
+                    id: 1,
+                    tier: 1,
+                    user: user_identity.clone(),
+                },
+                Event::Refunded {
+                    amount: 512,
+                    id: 1,
+                    user: user_identity,
+                },
+            ],
+            events
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod active_campaigns;
+mod activity;
+mod daily_volume;
+mod index;
+mod top_backers;
@@ -0,0 +1,119 @@
+use crate::utils::{
+    abi_calls::{create_campaign, pledge, transfer_pledge, unpledge},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+use fundraiser_indexer::{Backer, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn orders_backers_by_current_pledge() {
+        let (author, user, user2, asset, mut indexer) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        mint(&asset.contract, 200, user2.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(
+                &author.contract,
+                &asset.id,
+                Identity::Address(author.wallet.address()),
+                100,
+                512,
+            )
+            .await,
+            pledge(&user.contract, 1, &asset, 300).await,
+            pledge(&user2.contract, 1, &asset, 200).await,
+            unpledge(&user.contract, 1, 150).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(
+            vec![
+                Backer {
+                    amount: 200,
+                    user: Identity::Address(user2.wallet.address()),
+                },
+                Backer {
+                    amount: 150,
+                    user: Identity::Address(user.wallet.address()),
+                },
+            ],
+            indexer.store().top_backers(1, 10).unwrap()
+        );
+        assert_eq!(1, indexer.store().top_backers(1, 1).unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn excludes_backers_who_have_unpledged_everything() {
+        let (author, user, _, asset, mut indexer) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(
+                &author.contract,
+                &asset.id,
+                Identity::Address(author.wallet.address()),
+                100,
+                512,
+            )
+            .await,
+            pledge(&user.contract, 1, &asset, 300).await,
+            unpledge(&user.contract, 1, 300).await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert!(indexer.store().top_backers(1, 10).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn moves_transferred_pledges() {
+        let (author, user, user2, asset, mut indexer) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+
+        let responses = vec![
+            create_campaign(
+                &author.contract,
+                &asset.id,
+                Identity::Address(author.wallet.address()),
+                100,
+                512,
+            )
+            .await,
+            pledge(&user.contract, 1, &asset, 300).await,
+            transfer_pledge(
+                &user.contract,
+                1,
+                Identity::Address(user2.wallet.address()),
+                200,
+            )
+            .await,
+        ];
+        for response in responses {
+            indexer.index(&response.receipts, 0).unwrap();
+        }
+
+        assert_eq!(
+            vec![
+                Backer {
+                    amount: 200,
+                    user: Identity::Address(user2.wallet.address()),
+                },
+                Backer {
+                    amount: 100,
+                    user: Identity::Address(user.wallet.address()),
+                },
+            ],
+            indexer.store().top_backers(1, 10).unwrap()
+        );
+    }
+}
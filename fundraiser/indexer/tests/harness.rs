@@ -0,0 +1,5 @@
+// The receipts of each call are indexed with a timestamp chosen by the test because the SDK does
+// not expose the time at which a block was produced
+
+mod functions;
+mod utils;
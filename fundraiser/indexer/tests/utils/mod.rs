@@ -0,0 +1,370 @@
+use fuels::{
+    contract::contract::CallResponse,
+    prelude::*,
+    tx::{AssetId, ContractId},
+};
+use fuels_abigen_macro::abigen;
+use fundraiser_indexer::{Beneficiary, CampaignMetadata, Fundraiser, Identity, Indexer, Store};
+
+abigen!(Asset, "../tests/artifacts/asset/out/debug/asset-abi.json");
+
+// The NFT contract generates its own `Identity` which would clash with that of the fundraiser
+mod nft {
+    use fuels::prelude::*;
+    use fuels_abigen_macro::abigen;
+
+    abigen!(Nft, "../../NFT/out/debug/NFT-abi.json");
+}
+
+/// The sum of the shares of all beneficiaries of a campaign in basis points
+pub const TOTAL_SHARES: u64 = 10_000;
+
+pub struct Metadata {
+    pub contract: Fundraiser,
+    pub contract_id: ContractId,
+    pub wallet: LocalWallet,
+}
+
+pub struct MetaAsset {
+    pub contract: Asset,
+    pub id: ContractId,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn add_reward_tier(
+        contract: &Fundraiser,
+        id: u64,
+        minimum_pledge: u64,
+        nft: &ContractId,
+        quantity: u64,
+    ) -> CallResponse<()> {
+        contract
+            .add_reward_tier(id, minimum_pledge, *nft, quantity)
+            .set_contracts(&[*nft])
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn cancel_campaign(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract
+            .cancel_campaign(id)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn claim_pledges(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract
+            .claim_pledges(id)
+            .append_variable_outputs(5)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn create_campaign(
+        contract: &Fundraiser,
+        asset: &ContractId,
+        beneficiary: Identity,
+        deadline: u64,
+        target_amount: u64,
+    ) -> CallResponse<()> {
+        contract
+            .create_campaign(
+                *asset,
+                beneficiaries(beneficiary),
+                1,
+                deadline,
+                metadata(),
+                target_amount,
+            )
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn finalize(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.finalize(id).call().await.unwrap()
+    }
+
+    pub async fn pledge(
+        contract: &Fundraiser,
+        id: u64,
+        asset: &MetaAsset,
+        amount: u64,
+    ) -> CallResponse<()> {
+        let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
+        let call_params = CallParameters::new(Some(amount), Some(AssetId::from(*asset.id)));
+
+        contract
+            .pledge(id)
+            .tx_params(tx_params)
+            .call_params(call_params)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn pledge_for(
+        contract: &Fundraiser,
+        id: u64,
+        user: Identity,
+        asset: &MetaAsset,
+        amount: u64,
+    ) -> CallResponse<()> {
+        let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
+        let call_params = CallParameters::new(Some(amount), Some(AssetId::from(*asset.id)));
+
+        contract
+            .pledge_for(id, user)
+            .tx_params(tx_params)
+            .call_params(call_params)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn refund_batch(
+        contract: &Fundraiser,
+        id: u64,
+        start: u64,
+        count: u64,
+    ) -> CallResponse<()> {
+        contract
+            .refund_batch(id, start, count)
+            .append_variable_outputs(count.min(10))
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn request_extension(
+        contract: &Fundraiser,
+        id: u64,
+        extension: u64,
+    ) -> CallResponse<()> {
+        contract
+            .request_extension(id, extension)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn select_reward_tier(contract: &Fundraiser, id: u64, tier: u64) -> CallResponse<()> {
+        contract.select_reward_tier(id, tier).call().await.unwrap()
+    }
+
+    pub async fn set_private(contract: &Fundraiser, id: u64, private: bool) -> CallResponse<()> {
+        contract.set_private(id, private).call().await.unwrap()
+    }
+
+    pub async fn transfer_pledge(
+        contract: &Fundraiser,
+        id: u64,
+        to: Identity,
+        amount: u64,
+    ) -> CallResponse<()> {
+        contract
+            .transfer_pledge(id, to, amount)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn unpledge(contract: &Fundraiser, id: u64, amount: u64) -> CallResponse<()> {
+        contract
+            .unpledge(id, amount)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn update_campaign(
+        contract: &Fundraiser,
+        id: u64,
+        beneficiary: Identity,
+        deadline: u64,
+        target_amount: u64,
+    ) -> CallResponse<()> {
+        contract
+            .update_campaign(
+                id,
+                beneficiaries(beneficiary),
+                deadline,
+                metadata(),
+                target_amount,
+            )
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn vote_extension(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.vote_extension(id).call().await.unwrap()
+    }
+
+    fn beneficiaries(beneficiary: Identity) -> Vec<Beneficiary> {
+        let unused = Beneficiary {
+            recipient: Identity::Address(Address::zeroed()),
+            share: 0,
+        };
+
+        vec![
+            Beneficiary {
+                recipient: beneficiary,
+                share: TOTAL_SHARES,
+            },
+            unused.clone(),
+            unused.clone(),
+            unused,
+        ]
+    }
+
+    fn metadata() -> CampaignMetadata {
+        CampaignMetadata {
+            hash: [1u8; 32],
+            uri: format!("{:<64}", "https://fuel.network/campaigns/1"),
+        }
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata, Metadata, MetaAsset, Indexer) {
+        let num_wallets = 4;
+        let coins_per_wallet = 1;
+        let amount_per_coin = 1_000_000;
+
+        let config = WalletsConfig::new(
+            Some(num_wallets),
+            Some(coins_per_wallet),
+            Some(amount_per_coin),
+        );
+
+        let mut wallets = launch_provider_and_get_wallets(config).await;
+
+        let deployer_wallet = wallets.pop().unwrap();
+        let author_wallet = wallets.pop().unwrap();
+        let user_wallet = wallets.pop().unwrap();
+        let user2_wallet = wallets.pop().unwrap();
+
+        let id = Contract::deploy(
+            "../out/debug/fundraiser.bin",
+            &deployer_wallet,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+
+        let asset_id = Contract::deploy(
+            "../tests/artifacts/asset/out/debug/asset.bin",
+            &deployer_wallet,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+
+        let author = Metadata {
+            contract: Fundraiser::new(id.to_string(), author_wallet.clone()),
+            contract_id: id,
+            wallet: author_wallet,
+        };
+
+        let user = Metadata {
+            contract: Fundraiser::new(id.to_string(), user_wallet.clone()),
+            contract_id: id,
+            wallet: user_wallet,
+        };
+
+        let user2 = Metadata {
+            contract: Fundraiser::new(id.to_string(), user2_wallet.clone()),
+            contract_id: id,
+            wallet: user2_wallet,
+        };
+
+        let asset = MetaAsset {
+            contract: Asset::new(asset_id.to_string(), deployer_wallet),
+            id: asset_id,
+        };
+
+        author
+            .contract
+            .constructor(0, Identity::Address(author.wallet.address()))
+            .call()
+            .await
+            .unwrap();
+
+        let indexer = Indexer::new(id, Store::in_memory().unwrap());
+
+        (author, user, user2, asset, indexer)
+    }
+
+    pub async fn advance_to(wallet: &LocalWallet, height: u64) {
+        assert!(
+            block_height(wallet).await <= height,
+            "the block height is already past {}",
+            height
+        );
+
+        while block_height(wallet).await < height {
+            wallet
+                .transfer(
+                    &wallet.address(),
+                    1,
+                    AssetId::default(),
+                    TxParameters::default(),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    pub async fn block_height(wallet: &LocalWallet) -> u64 {
+        wallet
+            .get_provider()
+            .unwrap()
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .height
+            .0
+    }
+
+    /// Deploys an NFT contract administered by the fundraiser at `admin`
+    pub async fn deploy_nft(wallet: &LocalWallet, admin: &ContractId) -> ContractId {
+        let id = Contract::deploy(
+            "../../NFT/out/debug/NFT.bin",
+            wallet,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+
+        nft::Nft::new(id.to_string(), wallet.clone())
+            .constructor(true, nft::Identity::ContractId(*admin), 10)
+            .call()
+            .await
+            .unwrap();
+
+        id
+    }
+
+    pub async fn mint(contract: &Asset, amount: u64, address: Address) -> bool {
+        contract
+            .mint_and_send_to_address(amount, address)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
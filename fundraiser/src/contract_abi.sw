@@ -3,10 +3,31 @@ library contract_abi;
 dep data_structures;
 
 use std::{contract_id::ContractId, identity::Identity};
-use data_structures::{AssetInfo, Campaign, CampaignInfo, Pledge};
+use data_structures::{
+    AssetInfo,
+    AuthorStats,
+    Beneficiary,
+    Campaign,
+    CampaignEntry,
+    CampaignFilter,
+    CampaignInfo,
+    CampaignMetadata,
+    CampaignStatus,
+    ExtensionInfo,
+    MilestoneInfo,
+    Pledge,
+    QuoteTarget,
+    RewardSelection,
+    RewardTier,
+    SponsorPool,
+    Subscription,
+};
 
 abi Fundraiser {
-    #[storage(read, write)]fn create_campaign(asset: ContractId, beneficiary: Identity, deadline: u64, target_amount: u64);
+    #[storage(read, write)]fn constructor(fee: u64, fee_recipient: Identity);
+
+    #[storage(read, write)]fn create_campaign(asset: ContractId, beneficiaries: [Beneficiary;
+    4], category: u64, deadline: u64, metadata: CampaignMetadata, target_amount: u64);
 
     #[storage(read, write)]fn cancel_campaign(id: u64);
 
@@ -16,6 +37,63 @@ abi Fundraiser {
 
     #[storage(read, write)]fn unpledge(id: u64, amount: u64);
 
+    #[storage(read, write)]fn update_campaign(id: u64, beneficiaries: [Beneficiary;
+    4], deadline: u64, metadata: CampaignMetadata, target_amount: u64);
+
+    #[storage(read, write)]fn add_reward_tier(id: u64, minimum_pledge: u64, nft: ContractId, quantity: u64);
+
+    #[storage(read, write)]fn select_reward_tier(id: u64, tier: u64);
+
+    #[storage(read, write)]fn claim_reward(id: u64);
+
+    #[storage(read, write)]fn set_milestones(id: u64, tranches: u64, voting_period: u64);
+
+    #[storage(read, write)]fn propose_tranche(id: u64);
+
+    #[storage(read, write)]fn vote_tranche(id: u64, approve: bool);
+
+    #[storage(read, write)]fn release_tranche(id: u64);
+
+    #[storage(read, write)]fn abandon_milestones(id: u64);
+
+    #[storage(read, write)]fn reclaim_remainder(id: u64);
+
+    #[storage(read, write)]fn refund_batch(id: u64, start: u64, count: u64);
+
+    #[storage(read, write)]fn sponsor(id: u64);
+
+    #[storage(read, write)]fn refund_sponsor(id: u64);
+
+    #[storage(read, write)]fn withdraw_fees(asset: ContractId);
+
+    #[storage(read, write)]fn set_private(id: u64, private: bool);
+
+    #[storage(read, write)]fn add_to_allowlist(id: u64, user: Identity);
+
+    #[storage(read, write)]fn remove_from_allowlist(id: u64, user: Identity);
+
+    #[storage(read, write)]fn pledge_for(id: u64, user: Identity);
+
+    #[storage(read, write)]fn transfer_pledge(id: u64, to: Identity, amount: u64);
+
+    #[storage(read, write)]fn subscribe(id: u64, amount: u64, period: u64);
+
+    #[storage(read, write)]fn pull_subscription(subscription_id: u64);
+
+    #[storage(read, write)]fn cancel_subscription(subscription_id: u64);
+
+    #[storage(read, write)]fn request_extension(id: u64, extension: u64);
+
+    #[storage(read, write)]fn vote_extension(id: u64);
+
+    #[storage(read, write)]fn add_stretch_goal(id: u64, target: u64);
+
+    #[storage(read, write)]fn set_quote_target(id: u64, oracle: ContractId, target: u64);
+
+    #[storage(read, write)]fn record_price(id: u64);
+
+    #[storage(read, write)]fn finalize(id: u64);
+
     #[storage(read)]fn total_campaigns() -> u64;
 
     #[storage(read)]fn campaign_info(id: u64) -> CampaignInfo;
@@ -33,4 +111,63 @@ abi Fundraiser {
     #[storage(read)]fn asset_info_by_id(asset: ContractId) -> AssetInfo;
 
     #[storage(read)]fn asset_info_by_count(index: u64) -> AssetInfo;
+
+    #[storage(read)]fn reward_tier_count(id: u64) -> u64;
+
+    #[storage(read)]fn reward_tier(id: u64, tier: u64) -> RewardTier;
+
+    #[storage(read)]fn reward_selection(id: u64, user: Identity) -> RewardSelection;
+
+    #[storage(read)]fn milestone_info(id: u64) -> MilestoneInfo;
+
+    #[storage(read)]fn campaigns(start: u64, count: u64, filter: CampaignFilter) -> [CampaignEntry;
+    10];
+
+    #[storage(read)]fn user_campaigns(user: Identity, start: u64, count: u64, filter: CampaignFilter) -> [CampaignEntry;
+    10];
+
+    #[storage(read)]fn user_pledges(user: Identity, start: u64, count: u64, filter: CampaignFilter) -> [Pledge;
+    10];
+
+    #[storage(read)]fn backer_count(id: u64) -> u64;
+
+    #[storage(read)]fn backer(id: u64, index: u64) -> Identity;
+
+    #[storage(read)]fn sponsor_pool(id: u64) -> SponsorPool;
+
+    #[storage(read)]fn sponsor_matched(id: u64, user: Identity) -> u64;
+
+    #[storage(read)]fn fee() -> u64;
+
+    #[storage(read)]fn fee_recipient() -> Identity;
+
+    #[storage(read)]fn accrued_fees(asset: ContractId) -> u64;
+
+    #[storage(read)]fn is_private(id: u64) -> bool;
+
+    #[storage(read)]fn allowlisted(id: u64, user: Identity) -> bool;
+
+    #[storage(read)]fn active_campaign_count() -> u64;
+
+    #[storage(read)]fn active_campaign(index: u64) -> u64;
+
+    #[storage(read)]fn status_campaign_count(status: CampaignStatus) -> u64;
+
+    #[storage(read)]fn status_campaign(status: CampaignStatus, index: u64) -> u64;
+
+    #[storage(read)]fn category_campaign_count(category: u64) -> u64;
+
+    #[storage(read)]fn category_campaign(category: u64, index: u64) -> u64;
+
+    #[storage(read)]fn extension_info(id: u64) -> ExtensionInfo;
+
+    #[storage(read)]fn quote_target(id: u64) -> QuoteTarget;
+
+    #[storage(read)]fn author_stats(author: Identity) -> AuthorStats;
+
+    #[storage(read)]fn author_raised(author: Identity, asset: ContractId) -> u64;
+
+    #[storage(read)]fn subscription_count() -> u64;
+
+    #[storage(read)]fn subscription(subscription_id: u64) -> Subscription;
 }
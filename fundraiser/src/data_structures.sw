@@ -1,6 +1,7 @@
 library data_structures;
 
 use std::{contract_id::ContractId, identity::Identity};
+use core::ops::Eq;
 
 /// Used to track the total amount pledged to an asset
 pub struct AssetInfo {
@@ -12,12 +13,93 @@ pub struct AssetInfo {
     exists: bool,
 }
 
+/// The track record of a user across the campaigns that they have created
+pub struct AuthorStats {
+    /// The number of campaigns that the author has cancelled
+    cancelled: u64,
+
+    /// The number of campaigns that the author has created
+    created: u64,
+
+    /// The number of campaigns that have been finalized after failing to reach their target
+    failed: u64,
+
+    /// The number of campaigns that the author has claimed
+    successful: u64,
+}
+
 /// Used to track the campaigns that a user has created
 pub struct Campaign {
     /// The unique identifier for the campaign
     id: u64,
 }
 
+/// Off-chain description of a campaign which allows an interface to display it
+pub struct CampaignMetadata {
+    /// Hash of the content (title, description etc.) that the `uri` points to
+    hash: b256,
+
+    /// Location of the content, padded with spaces when shorter than the fixed length
+    uri: str[64],
+}
+
+/// A recipient of the proceeds of a successful campaign
+pub struct Beneficiary {
+    /// The user to whom their share of the proceeds will be sent
+    recipient: Identity,
+
+    /// The share of the proceeds in basis points where 0 marks an unused slot
+    share: u64,
+}
+
+/// Used to select which campaigns are returned when paginating
+pub enum CampaignFilter {
+    /// Every campaign regardless of its state
+    All: (),
+
+    /// Campaigns whose status, which is only updated by a cancellation, claim or finalization,
+    /// matches
+    Status: CampaignStatus,
+}
+
+/// The stage of its lifecycle that a campaign is in
+///
+/// A campaign is created as `Active` and moves to exactly one of the other states which are final
+/// except for `Succeeded` which is followed by `Claimed`
+pub enum CampaignStatus {
+    /// Accepting pledges until the deadline and awaiting a claim or finalization afterwards
+    Active: (),
+
+    /// Cancelled by the author before the deadline
+    Cancelled: (),
+
+    /// The author has claimed the total pledge
+    Claimed: (),
+
+    /// Finalized after the deadline without having reached the target
+    Failed: (),
+
+    /// Finalized after the deadline having reached the target and awaiting a claim
+    Succeeded: (),
+}
+
+impl Eq for CampaignStatus {
+    fn eq(self, other: Self) -> bool {
+        match(self, other) {
+            (CampaignStatus::Active, CampaignStatus::Active) => true, (CampaignStatus::Cancelled, CampaignStatus::Cancelled) => true, (CampaignStatus::Claimed, CampaignStatus::Claimed) => true, (CampaignStatus::Failed, CampaignStatus::Failed) => true, (CampaignStatus::Succeeded, CampaignStatus::Succeeded) => true, _ => false, 
+        }
+    }
+}
+
+/// A campaign returned when paginating alongside its identifier
+pub struct CampaignEntry {
+    /// The unique identifier for the campaign where 0 is the sentinel for an empty entry
+    id: u64,
+
+    /// Information about the campaign
+    info: CampaignInfo,
+}
+
 /// General data structure containing information about a campaign
 pub struct CampaignInfo {
     /// The user who has created the campaign
@@ -26,18 +108,29 @@ pub struct CampaignInfo {
     /// The asset that this campaign accepts as a deposit
     asset: ContractId,
 
-    /// The user to whom the funds will be sent to upon a successful campaign
-    beneficiary: Identity,
-
-    /// Whether the campaign has been cancelled by the author
-    cancelled: bool,
+    /// The users to whom the funds will be split between upon a successful campaign
+    beneficiaries: [Beneficiary;
+    4],
 
-    /// Whether the campaign has been claimed by the author
-    claimed: bool,
+    /// The topic of the campaign which is used to discover campaigns by category
+    category: u64,
 
     /// The end time for the campaign after which it becomes locked
     deadline: u64,
 
+    /// Hash and location of the description of the campaign
+    metadata: CampaignMetadata,
+
+    /// The stage of its lifecycle that the campaign is in
+    status: CampaignStatus,
+
+    /// Additional targets above the `target_amount` in ascending order where 0 is an unused goal
+    stretch_goals: [u64;
+    4],
+
+    /// The number of stretch goals that the total pledge has reached
+    stretch_goals_reached: u64,
+
     /// The amount needed to deem the campaign a success
     target_amount: u64,
 
@@ -45,6 +138,55 @@ pub struct CampaignInfo {
     total_pledge: u64,
 }
 
+/// A request by the author to push the deadline of a campaign forward
+pub struct ExtensionInfo {
+    /// Whether the backers have approved the extension and the deadline has been extended
+    approved: bool,
+
+    /// The number of blocks added to the deadline upon approval
+    extension: u64,
+
+    /// Whether the author has requested an extension
+    requested: bool,
+
+    /// The total pledge of the users that have voted for the extension
+    yes_votes: u64,
+}
+
+/// Used to release the total pledge of a campaign in tranches which are approved by its backers
+pub struct MilestoneInfo {
+    /// Whether backers have rejected a tranche which allows them to reclaim the remainder
+    failed: bool,
+
+    /// The pledge weighted votes against releasing the current tranche
+    no_votes: u64,
+
+    /// Block height after which anyone can abandon the milestones if the author has not proposed
+    /// the next tranche
+    proposal_deadline: u64,
+
+    /// The number of tranches that have been sent to the beneficiaries
+    released: u64,
+
+    /// The amount of the total pledge that has been sent to the beneficiaries
+    released_amount: u64,
+
+    /// The number of tranches the total pledge is split into where 0 disables milestones
+    tranches: u64,
+
+    /// Block height after which the vote on the current tranche can be resolved
+    vote_deadline: u64,
+
+    /// Whether backers are currently voting on a tranche
+    voting: bool,
+
+    /// The number of blocks that backers have to vote on each tranche
+    voting_period: u64,
+
+    /// The pledge weighted votes for releasing the current tranche
+    yes_votes: u64,
+}
+
 /// Used to track the amount pledged by a user to a specific campaign
 pub struct Pledge {
     /// The amount pledged to a campaign
@@ -53,3 +195,93 @@ pub struct Pledge {
     /// The unique identifier for the campaign
     id: u64,
 }
+
+/// A target denominated in a quote unit which is valued by a price oracle
+pub struct QuoteTarget {
+    /// The contract which reports the price of the campaign asset in the quote unit
+    oracle: ContractId,
+
+    /// The price recorded after the deadline where 0 is the sentinel for not yet recorded
+    price: u64,
+
+    /// The value in the quote unit required to deem the campaign a success where 0 is the
+    /// sentinel for a campaign without a quote target
+    target: u64,
+}
+
+/// Used to track the reward tier that a user has selected for a campaign
+pub struct RewardSelection {
+    /// The position of the user in the list of backers that have selected the tier
+    position: u64,
+
+    /// The index of the selected tier where 0 is the sentinel for no selection
+    tier: u64,
+}
+
+/// A reward offered to backers of a campaign who pledge at least the minimum amount
+pub struct RewardTier {
+    /// The minimum amount a user must have pledged in order to select the tier
+    minimum_pledge: u64,
+
+    /// The NFT contract which mints a receipt to each backer upon a successful claim
+    nft: ContractId,
+
+    /// The maximum number of backers that are able to select the tier
+    quantity: u64,
+
+    /// The number of backers that have currently selected the tier
+    selected: u64,
+}
+
+/// Funds provided by a sponsor which match the pledges of users 1:1 up to a cap
+pub struct SponsorPool {
+    /// The total amount provided by the sponsor where 0 is the sentinel for no sponsor
+    cap: u64,
+
+    /// The amount of the pool that is currently counted towards the total pledge
+    matched: u64,
+
+    /// Whether the pool has been returned to the sponsor and no longer matches pledges
+    settled: bool,
+
+    /// The user who has provided the pool
+    sponsor: Identity,
+}
+
+/// A recurring pledge which the author of a campaign pulls from once per period
+pub struct Subscription {
+    /// The amount that the author can pull each period
+    amount: u64,
+
+    /// The user who has funded the subscription
+    backer: Identity,
+
+    /// The amount that remains to be pulled or withdrawn by the backer
+    balance: u64,
+
+    /// Whether the backer has cancelled the subscription and withdrawn the balance
+    cancelled: bool,
+
+    /// The campaign which specifies the asset, the author and the beneficiaries
+    id: u64,
+
+    /// The block height from which the next amount can be pulled
+    next_pull: u64,
+
+    /// The number of blocks between each pull
+    period: u64,
+}
+
+/// Whether the contract has been initialized with a platform fee
+pub enum State {
+    NotInitialized: (),
+    Initialized: (),
+}
+
+impl Eq for State {
+    fn eq(self, other: Self) -> bool {
+        match(self, other) {
+            (State::Initialized, State::Initialized) => true, (State::NotInitialized, State::NotInitialized) => true, _ => false, 
+        }
+    }
+}
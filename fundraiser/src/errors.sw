@@ -1,24 +1,78 @@
 library errors;
 
 pub enum CampaignError {
+    AllTranchesReleased: (),
+    AlreadySponsored: (),
     CampaignEnded: (),
+    CampaignFinalized: (),
+    CampaignHasPledges: (),
     CampaignHasBeenCancelled: (),
+    CampaignNotClaimed: (),
     DeadlineNotReached: (),
+    ExtensionAlreadyApproved: (),
+    ExtensionAlreadyRequested: (),
+    ExtensionNotRequested: (),
+    MilestonesFailed: (),
+    MilestonesNotEnabled: (),
+    MilestonesNotFailed: (),
+    NoQuoteTarget: (),
+    NotSponsored: (),
+    PriceAlreadyRecorded: (),
+    PriceNotRecorded: (),
+    PriceRecordingPeriodElapsed: (),
+    ProposalDeadlineNotReached: (),
+    RewardTierSoldOut: (),
+    SponsorPoolSettled: (),
+    StretchGoalLimitReached: (),
     TargetReached: (),
     TargetNotReached: (),
+    TrancheVoteEnded: (),
+    TrancheVoteInProgress: (),
+    TrancheVoteNotEnded: (),
+    TrancheVoteNotInProgress: (),
 }
 
 pub enum CreationError {
     ContractNotInitialized: (),
     DeadlineMustBeInTheFuture: (),
+    ExtensionCannotBeZero: (),
+    ExtensionTooLong: (),
+    FirstShareCannotBeZero: (),
+    FundraiserNotNftAdmin: (),
+    NftSupplyExceeded: (),
+    PeriodCannotBeZero: (),
+    QuantityCannotBeZero: (),
+    SharesMustSumToTotal: (),
+    StretchGoalMustExceedPreviousGoal: (),
     TargetAmountCannotBeZero: (),
+    TranchesCannotBeZero: (),
+    VotingPeriodCannotBeZero: (),
+    VotingPeriodTooLong: (),
+}
+
+pub enum InitializationError {
+    CannotReinitialize: (),
+    FeeTooLarge: (),
+}
+
+pub enum SubscriptionError {
+    BalanceExhausted: (),
+    PeriodNotElapsed: (),
+    SubscriptionCancelled: (),
 }
 
 pub enum UserError {
     AlreadyClaimed: (),
+    AlreadyVoted: (),
     AmountCannotBeZero: (),
+    CannotTransferToSelf: (),
     IncorrectAssetSent: (),
+    InsufficientPledge: (),
     InvalidID: (),
+    NoFeesToWithdraw: (),
+    NoRewardSelected: (),
+    NotAllowlisted: (),
+    RewardAlreadyClaimed: (),
     UnauthorizedUser: (),
     UserHasNotPledged: (),
 }
@@ -2,8 +2,46 @@ library events;
 
 dep data_structures;
 
-use std::identity::Identity;
-use data_structures::CampaignInfo;
+use std::{contract_id::ContractId, identity::Identity};
+use data_structures::{CampaignInfo, CampaignStatus, RewardTier};
+
+pub struct AbandonedMilestonesEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of the tranche that the author did not propose in time
+    tranche: u64,
+}
+
+pub struct AddedRewardTierEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// Information about the newly added tier
+    reward_tier: RewardTier,
+
+    /// The index of the tier in the campaign
+    tier: u64,
+}
+
+pub struct AddedStretchGoalEvent {
+    /// The position of the stretch goal starting from 1
+    goal: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The total pledge at which the stretch goal is reached
+    target: u64,
+}
+
+pub struct AllowlistedEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who is able to pledge to the private campaign
+    user: Identity,
+}
 
 pub struct CancelledCampaignEvent {
     /// The unique identifier for the campaign
@@ -15,6 +53,17 @@ pub struct ClaimedEvent {
     id: u64,
 }
 
+pub struct ClaimedRewardEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The index of the tier that the receipt was minted from
+    tier: u64,
+
+    /// The backer who has received the receipt
+    user: Identity,
+}
+
 pub struct CreatedCampaignEvent {
     /// The user who has created the campaign
     author: Identity,
@@ -26,6 +75,90 @@ pub struct CreatedCampaignEvent {
     id: u64,
 }
 
+pub struct ExtendedDeadlineEvent {
+    /// The deadline of the campaign after the extension
+    deadline: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+}
+
+pub struct FinalizedEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// Whether the campaign has succeeded or failed
+    status: CampaignStatus,
+}
+
+/// Every event is logged through this enum so that the discriminant identifies the event
+/// within the log of a transaction
+pub enum FundraiserEvent {
+    AbandonedMilestones: AbandonedMilestonesEvent,
+    AddedRewardTier: AddedRewardTierEvent,
+    AddedStretchGoal: AddedStretchGoalEvent,
+    Allowlisted: AllowlistedEvent,
+    CancelledCampaign: CancelledCampaignEvent,
+    Claimed: ClaimedEvent,
+    ClaimedReward: ClaimedRewardEvent,
+    CreatedCampaign: CreatedCampaignEvent,
+    ExtendedDeadline: ExtendedDeadlineEvent,
+    Finalized: FinalizedEvent,
+    Initialized: InitializedEvent,
+    Matched: MatchedEvent,
+    Pledged: PledgedEvent,
+    PledgedFor: PledgedForEvent,
+    ProposedTranche: ProposedTrancheEvent,
+    PulledSubscription: PulledSubscriptionEvent,
+    Reclaimed: ReclaimedEvent,
+    ReachedStretchGoal: ReachedStretchGoalEvent,
+    RecordedPrice: RecordedPriceEvent,
+    Refunded: RefundedEvent,
+    RejectedTranche: RejectedTrancheEvent,
+    ReleasedTranche: ReleasedTrancheEvent,
+    RemovedFromAllowlist: RemovedFromAllowlistEvent,
+    RemovedRewardSelection: RemovedRewardSelectionEvent,
+    RequestedExtension: RequestedExtensionEvent,
+    SelectedRewardTier: SelectedRewardTierEvent,
+    SetMilestones: SetMilestonesEvent,
+    SetPrivate: SetPrivateEvent,
+    SetQuoteTarget: SetQuoteTargetEvent,
+    Sponsored: SponsoredEvent,
+    SponsorRefunded: SponsorRefundedEvent,
+    Subscribed: SubscribedEvent,
+    TransferredPledge: TransferredPledgeEvent,
+    Unmatched: UnmatchedEvent,
+    Unpledged: UnpledgedEvent,
+    UnreachedStretchGoal: UnreachedStretchGoalEvent,
+    Unsubscribed: UnsubscribedEvent,
+    UpdatedCampaign: UpdatedCampaignEvent,
+    VotedExtension: VotedExtensionEvent,
+    VotedTranche: VotedTrancheEvent,
+    WithdrawnFees: WithdrawnFeesEvent,
+}
+
+pub struct InitializedEvent {
+    /// The user who has initialized the contract
+    author: Identity,
+
+    /// The platform fee in basis points deducted from successful campaigns
+    fee: u64,
+
+    /// The user who is able to withdraw the accrued fees
+    fee_recipient: Identity,
+}
+
+pub struct MatchedEvent {
+    /// The amount drawn from the sponsor pool
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user whose pledge has been matched
+    user: Identity,
+}
+
 pub struct PledgedEvent {
     /// The amount pledged
     amount: u64,
@@ -37,6 +170,237 @@ pub struct PledgedEvent {
     user: Identity,
 }
 
+pub struct PledgedForEvent {
+    /// The amount pledged on behalf of the user
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has sent the pledge
+    sender: Identity,
+
+    /// The user who has received the pledge
+    user: Identity,
+}
+
+pub struct ProposedTrancheEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of the tranche that backers are voting on
+    tranche: u64,
+
+    /// Block height after which the vote can be resolved
+    vote_deadline: u64,
+}
+
+pub struct PulledSubscriptionEvent {
+    /// The amount pulled from the balance of the subscription
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The unique identifier for the subscription
+    subscription_id: u64,
+}
+
+pub struct ReclaimedEvent {
+    /// The share of the unreleased pledge returned to the user
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has reclaimed
+    user: Identity,
+}
+
+pub struct ReachedStretchGoalEvent {
+    /// The position of the stretch goal starting from 1
+    goal: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The total pledge at which the stretch goal is reached
+    target: u64,
+}
+
+pub struct RecordedPriceEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The price used to value the total pledge in the quote unit
+    price: u64,
+}
+
+pub struct RefundedEvent {
+    /// The amount returned to the backer
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The backer who has been refunded
+    user: Identity,
+}
+
+pub struct RejectedTrancheEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of the tranche that backers have rejected
+    tranche: u64,
+}
+
+pub struct ReleasedTrancheEvent {
+    /// The amount sent to the beneficiaries
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of the tranche that backers have approved
+    tranche: u64,
+}
+
+pub struct RemovedFromAllowlistEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who is no longer able to pledge to the private campaign
+    user: Identity,
+}
+
+pub struct RemovedRewardSelectionEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The index of the tier that the user no longer qualifies for
+    tier: u64,
+
+    /// The user who has lost their selection
+    user: Identity,
+}
+
+pub struct RequestedExtensionEvent {
+    /// The number of blocks that the author wishes to add to the deadline
+    extension: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+}
+
+pub struct SelectedRewardTierEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The index of the selected tier
+    tier: u64,
+
+    /// The user who has selected the tier
+    user: Identity,
+}
+
+pub struct SetMilestonesEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of tranches the total pledge is split into
+    tranches: u64,
+
+    /// The number of blocks that backers have to vote on each tranche
+    voting_period: u64,
+}
+
+pub struct SetPrivateEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// Whether only allowlisted users are able to pledge to the campaign
+    private: bool,
+}
+
+pub struct SetQuoteTargetEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The contract which reports the price of the campaign asset
+    oracle: ContractId,
+
+    /// The value in the quote unit required to deem the campaign a success
+    target: u64,
+}
+
+pub struct SponsoredEvent {
+    /// The amount provided to the sponsor pool
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has sponsored the campaign
+    sponsor: Identity,
+}
+
+pub struct SponsorRefundedEvent {
+    /// The amount returned to the sponsor
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has sponsored the campaign
+    sponsor: Identity,
+}
+
+pub struct SubscribedEvent {
+    /// The amount that the author can pull each period
+    amount: u64,
+
+    /// The amount that the backer has funded the subscription with
+    balance: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of blocks between each pull
+    period: u64,
+
+    /// The unique identifier for the subscription
+    subscription_id: u64,
+
+    /// The user who has subscribed
+    user: Identity,
+}
+
+pub struct TransferredPledgeEvent {
+    /// The amount of the pledge that has been transferred
+    amount: u64,
+
+    /// The user who has transferred their pledge
+    from: Identity,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has received the pledge
+    to: Identity,
+}
+
+pub struct UnmatchedEvent {
+    /// The amount returned to the sponsor pool
+    amount: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user whose pledge is no longer matched
+    user: Identity,
+}
+
 pub struct UnpledgedEvent {
     /// The amount unpledged
     amount: u64,
@@ -47,3 +411,72 @@ pub struct UnpledgedEvent {
     /// The user who has unpledged
     user: Identity,
 }
+
+pub struct UnreachedStretchGoalEvent {
+    /// The position of the stretch goal starting from 1
+    goal: u64,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The total pledge at which the stretch goal is reached
+    target: u64,
+}
+
+pub struct UnsubscribedEvent {
+    /// The remaining balance returned to the backer
+    amount: u64,
+
+    /// The unique identifier for the subscription
+    subscription_id: u64,
+
+    /// The user who has cancelled the subscription
+    user: Identity,
+}
+
+pub struct UpdatedCampaignEvent {
+    /// Information about the entire campaign after the update
+    campaign_info: CampaignInfo,
+
+    /// The unique identifier for the campaign
+    id: u64,
+}
+
+pub struct VotedExtensionEvent {
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The user who has voted
+    user: Identity,
+
+    /// The amount the user has pledged which is used as the weight of the vote
+    weight: u64,
+}
+
+pub struct VotedTrancheEvent {
+    /// Whether the user has voted to release the tranche
+    approve: bool,
+
+    /// The unique identifier for the campaign
+    id: u64,
+
+    /// The number of the tranche that the user has voted on
+    tranche: u64,
+
+    /// The user who has voted
+    user: Identity,
+
+    /// The amount the user has pledged which is used as the weight of the vote
+    weight: u64,
+}
+
+pub struct WithdrawnFeesEvent {
+    /// The amount of fees withdrawn
+    amount: u64,
+
+    /// The asset in which the fees were accrued
+    asset: ContractId,
+
+    /// The user who has received the fees
+    recipient: Identity,
+}
@@ -4,13 +4,16 @@ dep contract_abi;
 dep data_structures;
 dep errors;
 dep events;
+dep nft_abi;
+dep oracle_abi;
 dep utils;
 
 use std::{
+    address::Address,
     block::height,
     chain::auth::{AuthError, msg_sender},
     constants::BASE_ASSET_ID,
-    context::{call_frames::msg_asset_id, msg_amount, this_balance},
+    context::{call_frames::{contract_id, msg_asset_id}, msg_amount, this_balance},
     contract_id::ContractId,
     identity::Identity,
     logging::log,
@@ -18,16 +21,104 @@ use std::{
     revert::{require, revert},
     storage::StorageMap,
     token::transfer,
+    u128::U128,
 };
 
 use contract_abi::Fundraiser;
-use data_structures::{AssetInfo, Campaign, CampaignInfo, Pledge};
-use errors::{CampaignError, CreationError, UserError};
-use events::{CancelledCampaignEvent, ClaimedEvent, CreatedCampaignEvent, PledgedEvent, UnpledgedEvent};
-use utils::validate_id;
+use data_structures::{
+    AssetInfo,
+    AuthorStats,
+    Beneficiary,
+    Campaign,
+    CampaignEntry,
+    CampaignFilter,
+    CampaignInfo,
+    CampaignMetadata,
+    CampaignStatus,
+    ExtensionInfo,
+    MilestoneInfo,
+    Pledge,
+    QuoteTarget,
+    RewardSelection,
+    RewardTier,
+    SponsorPool,
+    State,
+    Subscription,
+};
+use errors::{
+    CampaignError,
+    CreationError,
+    InitializationError,
+    SubscriptionError,
+    UserError,
+};
+use events::{
+    AbandonedMilestonesEvent,
+    AddedRewardTierEvent,
+    AddedStretchGoalEvent,
+    AllowlistedEvent,
+    CancelledCampaignEvent,
+    ClaimedEvent,
+    ClaimedRewardEvent,
+    CreatedCampaignEvent,
+    ExtendedDeadlineEvent,
+    FinalizedEvent,
+    FundraiserEvent,
+    InitializedEvent,
+    MatchedEvent,
+    PledgedEvent,
+    PledgedForEvent,
+    ProposedTrancheEvent,
+    PulledSubscriptionEvent,
+    RecordedPriceEvent,
+    ReachedStretchGoalEvent,
+    ReclaimedEvent,
+    RefundedEvent,
+    RejectedTrancheEvent,
+    ReleasedTrancheEvent,
+    RemovedFromAllowlistEvent,
+    RemovedRewardSelectionEvent,
+    RequestedExtensionEvent,
+    SelectedRewardTierEvent,
+    SetMilestonesEvent,
+    SetPrivateEvent,
+    SetQuoteTargetEvent,
+    SponsoredEvent,
+    SponsorRefundedEvent,
+    SubscribedEvent,
+    TransferredPledgeEvent,
+    UnmatchedEvent,
+    UnpledgedEvent,
+    UnreachedStretchGoalEvent,
+    UnsubscribedEvent,
+    UpdatedCampaignEvent,
+    VotedExtensionEvent,
+    VotedTrancheEvent,
+    WithdrawnFeesEvent,
+};
+use nft_abi::NFT;
+use oracle_abi::Oracle;
+use utils::{
+    matches_filter,
+    MAX_EXTENSION,
+    MAX_VOTING_PERIOD,
+    mul_div,
+    page_index,
+    PRICE_PRECISION,
+    PRICE_RECORDING_PERIOD,
+    PROPOSAL_PERIODS,
+    TOTAL_SHARES,
+    transfer_to_beneficiaries,
+    validate_beneficiaries,
+    validate_id,
+};
 
 storage {
-    /// Total number of unique assets used across all campaigns
+    /// Users who are able to pledge to a private campaign
+    /// Map(Campaign ID => Map(Identity => Allowlisted))
+    allowlist: StorageMap<(u64,
+    Identity), bool> = StorageMap {
+    }, /// Total number of unique assets used across all campaigns
     asset_count: u64 = 0,
 
     /// Direct look-up for asset data if the user wants to check via a known ID
@@ -37,6 +128,31 @@ storage {
     /// Map(1...asset_count => asset)
     asset_index: StorageMap<u64,
     ContractId> = StorageMap {
+    }, /// The total pledge of the campaigns that an author has claimed in each asset
+    /// Map(Identity => Map(Asset => Amount))
+    author_raised: StorageMap<(Identity,
+    ContractId), u64> = StorageMap {
+    }, /// The track record of each author across the campaigns that they have created
+    author_stats: StorageMap<Identity,
+    AuthorStats> = StorageMap {
+    }, /// The number of unique users that have pledged to a campaign
+    /// This should only be incremented.
+    /// Unpledging should not affect this number
+    /// Map(Campaign ID => Backer Count)
+    backer_count: StorageMap<u64,
+    u64> = StorageMap {
+    }, /// Users who have pledged to a campaign in order to refund them in batches
+    /// Map(Campaign ID => Map(1...backer_count => Identity))
+    backers: StorageMap<(u64,
+    u64), Identity> = StorageMap {
+    }, /// The number of campaigns that have been created in a category
+    /// Map(Category => Campaign Count)
+    category_campaign_count: StorageMap<u64,
+    u64> = StorageMap {
+    }, /// Campaigns that have been created in a category
+    /// Map(Category => Map(1...category_campaign_count => Campaign ID))
+    category_campaigns: StorageMap<(u64,
+    u64), u64> = StorageMap {
     }, /// The total number of unique campaigns that a user has created
     /// This should only be incremented
     /// Cancelling / Claiming should not affect this number
@@ -51,6 +167,32 @@ storage {
     /// Map(Campaign ID => CampaignInfo)
     campaign_info: StorageMap<u64,
     CampaignInfo> = StorageMap {
+    }, /// The weight with which a user has voted for the extension of a campaign
+    /// Map(Identity => Map(Campaign ID => Weight))
+    extension_votes: StorageMap<(Identity,
+    u64), u64> = StorageMap {
+    }, /// Requests by authors to push the deadline of a campaign forward
+    /// Map(Campaign ID => ExtensionInfo)
+    extensions: StorageMap<u64,
+    ExtensionInfo> = StorageMap {
+    }, /// The platform fee in basis points deducted from the pledges of successful campaigns
+    fee: u64 = 0,
+    /// The user who is able to withdraw the accrued fees
+    fee_recipient: Identity = Identity::Address(Address {
+        value: 0x0000000000000000000000000000000000000000000000000000000000000000,
+    }),
+    /// Fees that have been deducted and not yet withdrawn
+    /// Map(Asset => Accrued Fees)
+    fees: StorageMap<ContractId,
+    u64> = StorageMap {
+    }, /// Tranches in which the total pledge of a campaign is released to the beneficiaries
+    /// Map(Campaign ID => MilestoneInfo)
+    milestones: StorageMap<u64,
+    MilestoneInfo> = StorageMap {
+    }, /// The quantity of each NFT contract that has been promised to backers through reward tiers
+    /// Map(NFT => Reserved Quantity)
+    nft_reserved: StorageMap<ContractId,
+    u64> = StorageMap {
     }, /// The total number of unique campaigns that a user has pledged to
     /// This should only be incremented.
     /// Unpledging should not affect this number
@@ -65,11 +207,102 @@ storage {
     /// Map(Identity => Map(Campaign ID => Pledge History Index))
     pledge_history_index: StorageMap<(Identity,
     u64), u64> = StorageMap {
+    }, /// Campaigns which only accept pledges from the users on their allowlist
+    /// Map(Campaign ID => Private)
+    private_campaigns: StorageMap<u64,
+    bool> = StorageMap {
+    }, /// Targets of campaigns which are denominated in a quote unit
+    /// Map(Campaign ID => QuoteTarget)
+    quote_targets: StorageMap<u64,
+    QuoteTarget> = StorageMap {
+    }, /// Users who have currently selected a reward tier and can mint a receipt once the campaign is claimed
+    /// Map(Campaign ID => Map(Tier => Map(1...RewardTier.selected => Identity)))
+    reward_backers: StorageMap<(u64,
+    u64, u64), Identity> = StorageMap {
+    }, /// Whether a backer has minted the receipt of the reward tier that they selected
+    /// Map(Identity => Map(Campaign ID => Claimed))
+    reward_claimed: StorageMap<(Identity,
+    u64), bool> = StorageMap {
+    }, /// O(1) look-up of the tier that a user has selected in order to remove them from reward_backers
+    /// Map(Identity => Map(Campaign ID => RewardSelection))
+    reward_selection: StorageMap<(Identity,
+    u64), RewardSelection> = StorageMap {
+    }, /// The number of reward tiers that have been added to a campaign
+    /// Map(Campaign ID => Tier Count)
+    reward_tier_count: StorageMap<u64,
+    u64> = StorageMap {
+    }, /// Rewards offered by a campaign to its backers
+    /// Map(Campaign ID => Map(1...reward_tier_count => RewardTier))
+    reward_tiers: StorageMap<(u64,
+    u64), RewardTier> = StorageMap {
+    }, /// The amount of the sponsor pool that is matching the pledge of a user
+    /// Map(Identity => Map(Campaign ID => Matched Amount))
+    sponsor_matched: StorageMap<(Identity,
+    u64), u64> = StorageMap {
+    }, /// Funds provided by a sponsor to match the pledges of users
+    /// Map(Campaign ID => SponsorPool)
+    sponsor_pools: StorageMap<u64,
+    SponsorPool> = StorageMap {
+    }, /// The initialization state of the contract
+    state: State = State::NotInitialized,
+    /// The number of campaigns that currently have each status
+    /// Map(CampaignStatus => Count)
+    status_campaign_count: StorageMap<CampaignStatus,
+    u64> = StorageMap {
+    }, /// O(1) look-up of the position of a campaign in status_campaigns in order to move it
+    /// Map(Campaign ID => 1...status_campaign_count)
+    status_campaign_position: StorageMap<u64,
+    u64> = StorageMap {
+    }, /// Campaigns indexed by their current status
+    /// Map((CampaignStatus, 1...status_campaign_count) => Campaign ID)
+    status_campaigns: StorageMap<(CampaignStatus,
+    u64), u64> = StorageMap {
+    },     /// The number of subscriptions created by all users
+    subscription_count: u64 = 0,
+    /// Recurring pledges which the author of a campaign pulls from once per period
+    /// Map(1...subscription_count => Subscription)
+    subscriptions: StorageMap<u64,
+    Subscription> = StorageMap {
     }, /// The number of campaigns created by all users
     total_campaigns: u64 = 0,
+    /// The last tranche that a user has voted on to prevent voting twice on the same tranche
+    /// Map(Identity => Map(Campaign ID => Tranche))
+    tranche_votes: StorageMap<(Identity,
+    u64), u64> = StorageMap {
+    },
 }
 
 impl Fundraiser for Contract {
+    /// Sets the platform fee and the user who is able to withdraw it which unlocks the creation of
+    /// campaigns
+    ///
+    /// Anyone is able to call the constructor once. Deploying a contract and calling it cannot
+    /// happen in the same transaction on Fuel, so another user is able to initialize the contract
+    /// between its deployment and the first call of the deployer. The deployer must check `fee`
+    /// and `fee_recipient` after initializing and redeploy with a new salt if they do not match.
+    ///
+    /// # Arguments
+    ///
+    /// * `fee` - The fee in basis points deducted from the pledges of successful campaigns
+    /// * `fee_recipient` - The user who is able to withdraw the accrued fees
+    ///
+    /// # Reverts
+    ///
+    /// * When the constructor is called more than once
+    /// * When the `fee` is greater than 10,000 basis points
+    #[storage(read, write)]fn constructor(fee: u64, fee_recipient: Identity) {
+        require(storage.state == State::NotInitialized, InitializationError::CannotReinitialize);
+        require(fee <= TOTAL_SHARES, InitializationError::FeeTooLarge);
+
+        storage.fee = fee;
+        storage.fee_recipient = fee_recipient;
+        storage.state = State::Initialized;
+
+        log(FundraiserEvent::Initialized(InitializedEvent {
+            author: msg_sender().unwrap(), fee, fee_recipient
+        }));
+    }
+
     /// Creates a data structure representing a campaign that users can pledge to
     ///
     /// Instead of having a contract per campaign we create an internal representation for the data
@@ -78,30 +311,44 @@ impl Fundraiser for Contract {
     /// # Arguments
     ///
     /// * `asset` - A coin that the campaign accepts as a pledge
-    /// * `beneficiary` - The recipient to whom the pledge will be sent to upon a successful campaign
+    /// * `beneficiaries` - The recipients between whom the pledge will be split upon a successful campaign
+    /// * `category` - The topic of the campaign which is used to discover campaigns by category
     /// * `deadline` - Block height used to dictate the end time of a campaign
+    /// * `metadata` - Hash and location of the description of the campaign
     /// * `target_amount` - The amount of `asset` required to deem the campaign a success
     ///
     /// # Reverts
     ///
+    /// * When the contract has not been initialized
     /// * When `asset` is the BASE_ASSET
     /// * When the `deadline` is not ahead of the current block height
     /// * When the `target_amount` is 0
-    #[storage(read, write)]fn create_campaign(asset: ContractId, beneficiary: Identity, deadline: u64, target_amount: u64) {
+    /// * When the first beneficiary has a share of 0
+    /// * When the shares of the `beneficiaries` do not sum to 10,000 basis points
+    #[storage(read, write)]fn create_campaign(asset: ContractId, beneficiaries: [Beneficiary;
+    4], category: u64, deadline: u64, metadata: CampaignMetadata, target_amount: u64) {
+        // Campaigns can only be created once the platform fee has been set
+        require(storage.state == State::Initialized, CreationError::ContractNotInitialized);
+
         // Users cannot interact with a campaign that has already ended (is in the past)
         require(height() < deadline, CreationError::DeadlineMustBeInTheFuture);
 
         // A campaign must have a target to reach and therefore 0 is an invalid amount
         require(0 < target_amount, CreationError::TargetAmountCannotBeZero);
 
+        // The entire pledge must be split between the beneficiaries
+        validate_beneficiaries(beneficiaries);
+
         let user = msg_sender().unwrap();
 
         // Create an internal representation of a campaign
         let campaign_info = CampaignInfo {
             asset, author: user,
-            beneficiary, cancelled: false,
-            claimed: false,
-            deadline, target_amount, total_pledge: 0,
+            beneficiaries, category, deadline,
+            metadata, status: CampaignStatus::Active,
+            stretch_goals: [0, 0, 0, 0],
+            stretch_goals_reached: 0,
+            target_amount, total_pledge: 0,
         };
 
         // Keep track of new assets
@@ -135,10 +382,23 @@ impl Fundraiser for Contract {
             id: storage.total_campaigns
         });
 
+        // Count the campaign towards the track record of the author
+        let mut author_stats = storage.author_stats.get(user);
+        author_stats.created = author_stats.created + 1;
+        storage.author_stats.insert(user, author_stats);
+
+        // Index the campaign by its category for discovery via iteration over numbers
+        let category_campaign_count = storage.category_campaign_count.get(category) + 1;
+        storage.category_campaign_count.insert(category, category_campaign_count);
+        storage.category_campaigns.insert((category, category_campaign_count), storage.total_campaigns);
+
+        // The campaign is active until it has been cancelled, claimed or finalized
+        add_status_campaign(storage.total_campaigns, CampaignStatus::Active);
+
         // We have changed the state by adding a new data structure therefore we log it
-        log(CreatedCampaignEvent {
+        log(FundraiserEvent::CreatedCampaign(CreatedCampaignEvent {
             author: user, campaign_info, id: storage.total_campaigns
-        });
+        }));
     }
 
     /// Marks a campaign as cancelled preventing further pledges or a claim to be made
@@ -168,21 +428,47 @@ impl Fundraiser for Contract {
 
         // User cannot a campaign that has already been cancelled
         // Given the logic below this is unnecessary aside from ignoring event spam
-        require(!campaign_info.cancelled, CampaignError::CampaignHasBeenCancelled);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
 
         // Mark the campaign as cancelled
-        campaign_info.cancelled = true;
+        campaign_info.status = CampaignStatus::Cancelled;
+
+        // The sponsor pool no longer counts towards the total pledge and is returned in full
+        let pool = storage.sponsor_pools.get(id);
+        if pool.cap != 0 && !pool.settled {
+            campaign_info.total_pledge = campaign_info.total_pledge - pool.matched;
+            settle_sponsor_pool(id, campaign_info.asset, pool.cap);
+
+            // The total pledge may have fallen below the stretch goals that it had reached
+            campaign_info.stretch_goals_reached = update_stretch_goals(id, campaign_info);
+        }
 
         // Overwrite the previous campaign (which has not been cancelled) with the updated version
         storage.campaign_info.insert(id, campaign_info);
 
+        // A cancelled campaign is no longer listed as active
+        remove_status_campaign(id, CampaignStatus::Active);
+        add_status_campaign(id, CampaignStatus::Cancelled);
+
+        // None of the rewards will be minted so the NFT supply that they reserve is returned
+        release_reward_tiers(id, false);
+
+        // Count the cancellation towards the track record of the author
+        let mut author_stats = storage.author_stats.get(campaign_info.author);
+        author_stats.cancelled = author_stats.cancelled + 1;
+        storage.author_stats.insert(campaign_info.author, author_stats);
+
         // We have updated the state of a campaign therefore we must log it
-        log(CancelledCampaignEvent {
+        log(FundraiserEvent::CancelledCampaign(CancelledCampaignEvent {
             id
-        });
+        }));
     }
 
-    /// Transfers the total pledge to the beneficiary
+    /// Transfers the total pledge, minus the platform fee, to the beneficiaries according to their
+    /// shares
+    ///
+    /// If the campaign uses milestones then nothing is transferred and instead the campaign is
+    /// locked so that the author can propose tranches to be released
     ///
     /// # Arguments
     ///
@@ -193,9 +479,10 @@ impl Fundraiser for Contract {
     /// * When the `id` is either 0 or greater than the total number of campaigns created
     /// * When the user is not the author of the campaign
     /// * When the deadline has not been reached
-    /// * When the total pledge has not reached the minimum `target_amount`
+    /// * When the total pledge has not reached the minimum `target_amount` or the quote target
     /// * When the campaign has already been claimed
     /// * When the campaign has already been cancelled
+    /// * When the campaign has been finalized as failed
     #[storage(read, write)]fn claim_pledges(id: u64) {
         // User cannot interact with a non-existent campaign
         validate_id(id, storage.total_campaigns);
@@ -210,28 +497,62 @@ impl Fundraiser for Contract {
         // (campaign has naturally ended i.e. has not been cancelled)
         require(campaign_info.deadline <= height(), CampaignError::DeadlineNotReached);
 
-        // The author can only claim the pledges once the target amount has been reached otherwise
-        // users should be able to withdraw
-        require(campaign_info.target_amount <= campaign_info.total_pledge, CampaignError::TargetNotReached);
+        let status = effective_status(id, campaign_info);
 
         // The author can only claim once to prevent the entire contract from being drained
-        require(!campaign_info.claimed, UserError::AlreadyClaimed);
+        require(status != CampaignStatus::Claimed, UserError::AlreadyClaimed);
 
         // The author cannot claim after they have cancelled the campaign regardless of any other
         // checks
-        require(!campaign_info.cancelled, CampaignError::CampaignHasBeenCancelled);
+        require(status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // The author can only claim the pledges once the campaign has succeeded otherwise users
+        // should be able to withdraw
+        require(status == CampaignStatus::Succeeded, CampaignError::TargetNotReached);
+
+        // A claimed campaign is no longer listed as active or succeeded
+        remove_status_campaign(id, campaign_info.status);
+        add_status_campaign(id, CampaignStatus::Claimed);
 
         // Mark the campaign as claimed and overwrite the previous state with the updated version
-        campaign_info.claimed = true;
+        campaign_info.status = CampaignStatus::Claimed;
         storage.campaign_info.insert(id, campaign_info);
 
-        // Transfer the total pledged to this campaign to the beneficiary
-        transfer(campaign_info.total_pledge, campaign_info.asset, campaign_info.beneficiary);
+        // Count the success and the amount raised towards the track record of the author
+        let mut author_stats = storage.author_stats.get(campaign_info.author);
+        author_stats.successful = author_stats.successful + 1;
+        storage.author_stats.insert(campaign_info.author, author_stats);
+
+        let raised = storage.author_raised.get((campaign_info.author, campaign_info.asset));
+        storage.author_raised.insert((campaign_info.author, campaign_info.asset), raised + campaign_info.total_pledge);
+
+        // Transfer the total pledged to this campaign to the beneficiaries unless the campaign uses
+        // milestones in which case the pledge is released in tranches approved by the backers
+        let mut milestone_info = storage.milestones.get(id);
+        if milestone_info.tranches == 0 {
+            let amount = deduct_fee(campaign_info.total_pledge, campaign_info.asset);
+            transfer_to_beneficiaries(amount, campaign_info.asset, campaign_info.beneficiaries);
+        } else {
+            // The author must propose the first tranche in time otherwise the backers can abandon
+            // the milestones and reclaim their pledges
+            milestone_info.proposal_deadline = height() + milestone_info.voting_period * PROPOSAL_PERIODS;
+            storage.milestones.insert(id, milestone_info);
+        }
+
+        // The part of the sponsor pool that has not matched any pledge is returned to the sponsor
+        let pool = storage.sponsor_pools.get(id);
+        if pool.cap != 0 && !pool.settled {
+            settle_sponsor_pool(id, campaign_info.asset, pool.cap - pool.matched);
+        }
+
+        // Receipts which have not been selected will never be minted so the NFT supply that they
+        // reserve is returned. Each backer mints their own receipt through claim_reward
+        release_reward_tiers(id, true);
 
         // We have updated the state of a campaign therefore we must log it
-        log(ClaimedEvent {
+        log(FundraiserEvent::Claimed(ClaimedEvent {
             id
-        });
+        }));
     }
 
     /// Allows a user to pledge any amount of the campaign asset towards the campaign goal
@@ -250,80 +571,9 @@ impl Fundraiser for Contract {
     /// * When the user attempts to pledge when the deadline has been reached
     /// * When the user pledges a different asset to the one specified in the campaign
     /// * When the user pledges after the campaign has been cancelled
+    /// * When the campaign is private and the user is not on its allowlist
     #[storage(read, write)]fn pledge(id: u64) {
-        // User cannot interact with a non-existent campaign
-        validate_id(id, storage.total_campaigns);
-
-        // Retrieve the campaign in order to check its data / update it
-        let mut campaign_info = storage.campaign_info.get(id);
-
-        // The users should only have the ability to pledge to campaigns that have not reached their
-        // deadline (ended naturally - not been cancelled)
-        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
-
-        // The campaign specifies an asset that it accepts therefore the user must pledge the correct
-        // asset in order to update the state of the campaign
-        require(campaign_info.asset == msg_asset_id(), UserError::IncorrectAssetSent);
-
-        // A user cannot pledge zero since it does not make sense to do so
-        require(0 < msg_amount(), UserError::AmountCannotBeZero);
-
-        // The user should not be able to continue to pledge if the campaign has been cancelled
-        // Given the logic below it's unnecessary but it makes sense to stop them
-        require(!campaign_info.cancelled, CampaignError::CampaignHasBeenCancelled);
-
-        // Use the user's pledges as an ID / way to index this new pledge
-        let user = msg_sender().unwrap();
-        let pledge_count = storage.pledge_count.get(user);
-
-        // Fetch the index to see if the user has pledged to this campaign before or if this is a
-        // pledge to a new campaign
-        let pledge_history_index = storage.pledge_history_index.get((user, id));
-
-        // Pledging to a campaign that they have already pledged to
-        if pledge_history_index != 0 {
-            // 0 is the sentinel therefore they have pledged to this ID (campaign)
-            // increment their previous amount with the current pledge and update their pledge
-            let mut pledge = storage.pledge_history.get((user, pledge_history_index));
-            pledge.amount = pledge.amount + msg_amount();
-
-            storage.pledge_history.insert((user, pledge_history_index), pledge);
-        }
-        // Pledging to a new campaign
-        else {
-            // First time pledge to this campaign therefore increment everything by 1
-            storage.pledge_count.insert(user, pledge_count + 1);
-
-            // Store the data structure required to look up the campaign they have pledged to, also
-            // track how much they have pledged so that they can withdraw the correct amount.
-            // Moreover, this can be used to show the user how much they have pledged to any campaign
-            storage.pledge_history.insert((user, pledge_count + 1), Pledge {
-                amount: msg_amount(), id
-            });
-
-            // Since we use the campaign ID to interact with the contract use the ID as a key for
-            // a reverse look-up. Value is the 1st pledge (count)
-            storage.pledge_history_index.insert((user, id), pledge_count + 1);
-        }
-
-        // The user has pledged therefore we increment the total amount that this campaign has
-        // received.
-        campaign_info.total_pledge = campaign_info.total_pledge + msg_amount();
-
-        // Campaign state has been updated therefore overwrite the previous version with the new
-        storage.campaign_info.insert(id, campaign_info);
-
-        // Update the asset amount to track the addition of the new pledge
-        let mut asset_info = storage.asset_info.get(campaign_info.asset);
-        asset_info.amount = asset_info.amount + msg_amount();
-
-        // Update asset state
-        storage.asset_info.insert(campaign_info.asset, asset_info);
-
-        // We have updated the state of a campaign therefore we must log it
-        log(PledgedEvent {
-            amount: msg_amount(), id, user
-        });
+        receive_pledge(id, msg_sender().unwrap());
     }
 
     /// Allows a user to unpledge an amount of the campaign asset that they have pledged
@@ -340,8 +590,12 @@ impl Fundraiser for Contract {
     /// # Reverts
     ///
     /// * When the `id` is either 0 or greater than the total number of campaigns created
-    /// * When the user attempts to unpledge after the deadline and `target_amount` have been reached
+    /// * When the user attempts to unpledge after the deadline from a campaign that has reached
+    ///   its target or has been claimed
     /// * When the user has not pledged to the campaign represented by the `id`
+    ///
+    /// If the remaining pledge falls below the minimum of the selected reward tier then the
+    /// selection is removed
     #[storage(read, write)]fn unpledge(id: u64, amount: u64) {
         // User cannot interact with a non-existent campaign
         validate_id(id, storage.total_campaigns);
@@ -353,9 +607,12 @@ impl Fundraiser for Contract {
         let mut campaign_info = storage.campaign_info.get(id);
 
         // A user should be able to unpledge at any point except if the deadline has been reached
-        // and the author has claimed
+        // and the campaign has succeeded, whether or not it has been finalized, or the author has
+        // claimed
         if campaign_info.deadline <= height() {
-            require(!campaign_info.claimed, UserError::AlreadyClaimed);
+            let status = effective_status(id, campaign_info);
+            require(status != CampaignStatus::Claimed, UserError::AlreadyClaimed);
+            require(status != CampaignStatus::Succeeded, CampaignError::TargetReached);
         }
 
         // Check if the user has pledged to the campaign they are attempting to unpledge from
@@ -380,9 +637,39 @@ impl Fundraiser for Contract {
         // Lower the campaign total pledge by the amount the user has unpledged
         campaign_info.total_pledge = campaign_info.total_pledge - amount;
 
+        // The sponsor only matches what the user has pledged therefore any match above the
+        // remaining pledge is returned to the sponsor pool
+        let mut pool = storage.sponsor_pools.get(id);
+        let user_matched = storage.sponsor_matched.get((user, id));
+        if !pool.settled && pledge.amount < user_matched {
+            let unmatched = user_matched - pledge.amount;
+
+            pool.matched = pool.matched - unmatched;
+            storage.sponsor_pools.insert(id, pool);
+            storage.sponsor_matched.insert((user, id), pledge.amount);
+
+            campaign_info.total_pledge = campaign_info.total_pledge - unmatched;
+
+            log(FundraiserEvent::Unmatched(UnmatchedEvent {
+                amount: unmatched, id, user
+            }));
+        }
+
         // Update the state of their pledge with the new version
         storage.pledge_history.insert((user, pledge_history_index), pledge);
 
+        // The user can no longer vote for an extension with more than they have pledged
+        reduce_extension_vote(id, user, pledge.amount);
+
+        // The user may no longer qualify for the reward tier that they have selected
+        let selection = storage.reward_selection.get((user, id));
+        if selection.tier != 0 && pledge.amount < storage.reward_tiers.get((id, selection.tier)).minimum_pledge {
+            remove_reward_selection(id, user);
+        }
+
+        // The total pledge may have fallen below the stretch goals that it had reached
+        campaign_info.stretch_goals_reached = update_stretch_goals(id, campaign_info);
+
         // Update the campaign state with the updated version as well
         storage.campaign_info.insert(id, campaign_info);
 
@@ -397,100 +684,2187 @@ impl Fundraiser for Contract {
         transfer(amount, campaign_info.asset, user);
 
         // We have updated the state of a campaign therefore we must log it
-        log(UnpledgedEvent {
+        log(FundraiserEvent::Unpledged(UnpledgedEvent {
             amount, id, user
-        });
+        }));
     }
 
-    /// Returns the total number of campaigns that have been created by all users
-    #[storage(read)]fn total_campaigns() -> u64 {
-        storage.total_campaigns
+    /// Allows the author to change the details of a campaign before anyone has pledged to it
+    ///
+    /// Once users have pledged they have done so under the conditions that were presented to them
+    /// therefore the details are only editable while the total pledge is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `beneficiaries` - The recipients between whom the pledge will be split upon a successful campaign
+    /// * `deadline` - Block height used to dictate the end time of a campaign
+    /// * `metadata` - Hash and location of the description of the campaign
+    /// * `target_amount` - The amount of `asset` required to deem the campaign a success
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the total pledge is not 0
+    /// * When the campaign has a sponsor
+    /// * When the new `deadline` is not ahead of the current block height
+    /// * When the new `target_amount` is 0
+    /// * When the new `target_amount` is not less than the first stretch goal
+    /// * When the first of the new `beneficiaries` has a share of 0
+    /// * When the shares of the new `beneficiaries` do not sum to 10,000 basis points
+    #[storage(read, write)]fn update_campaign(id: u64, beneficiaries: [Beneficiary;
+    4], deadline: u64, metadata: CampaignMetadata, target_amount: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Retrieve the campaign in order to check its data / update it
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can change it
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        // A campaign that has ended or been cancelled is no longer editable
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // Users and sponsors who have committed funds must not have the conditions changed
+        // underneath them
+        require(campaign_info.total_pledge == 0, CampaignError::CampaignHasPledges);
+        require(storage.sponsor_pools.get(id).cap == 0, CampaignError::AlreadySponsored);
+
+        // The new details are subject to the same restrictions as upon creation
+        require(height() < deadline, CreationError::DeadlineMustBeInTheFuture);
+        require(0 < target_amount, CreationError::TargetAmountCannotBeZero);
+        validate_beneficiaries(beneficiaries);
+
+        // Stretch goals that have been announced must remain above the target
+        let first_goal = campaign_info.stretch_goals[0];
+        require(first_goal == 0 || target_amount < first_goal, CreationError::StretchGoalMustExceedPreviousGoal);
+
+        campaign_info.beneficiaries = beneficiaries;
+        campaign_info.deadline = deadline;
+        campaign_info.metadata = metadata;
+        campaign_info.target_amount = target_amount;
+
+        // Overwrite the previous campaign with the updated version
+        storage.campaign_info.insert(id, campaign_info);
+
+        // We have updated the state of a campaign therefore we must log it
+        log(FundraiserEvent::UpdatedCampaign(UpdatedCampaignEvent {
+            campaign_info, id
+        }));
     }
 
-    /// Returns information about the specified campaign
+    /// Adds a reward tier to a campaign which backers can select once they have pledged enough
+    ///
+    /// Upon a successful claim each backer that has selected the tier is minted a receipt from the
+    /// `nft` contract. The fundraiser must be the admin of that contract with access control set so
+    /// that only the fundraiser is able to mint receipts.
     ///
     /// # Arguments
     ///
     /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `minimum_pledge` - The amount a user must have pledged in order to select the tier
+    /// * `nft` - The NFT contract used to mint receipts for the tier
+    /// * `quantity` - The maximum number of backers that can select the tier
     ///
     /// # Reverts
     ///
     /// * When the `id` is either 0 or greater than the total number of campaigns created
-    #[storage(read)]fn campaign_info(id: u64) -> CampaignInfo {
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the `quantity` is 0
+    /// * When the fundraiser is not the admin of the `nft` contract
+    /// * When the `quantity` exceeds the supply of the `nft` contract that has not been reserved
+    #[storage(read, write)]fn add_reward_tier(id: u64, minimum_pledge: u64, nft: ContractId, quantity: u64) {
         // User cannot interact with a non-existent campaign
         validate_id(id, storage.total_campaigns);
-        storage.campaign_info.get(id)
-    }
 
-    /// Returns the number of campaigns that the user has created
-    #[storage(read)]fn user_campaign_count(user: Identity) -> u64 {
-        storage.user_campaign_count.get(user)
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can offer rewards
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        // Rewards can only be added while users are able to pledge
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // A tier that nobody can select is pointless
+        require(0 < quantity, CreationError::QuantityCannotBeZero);
+
+        // Check up front that the receipts can be minted otherwise the claim would revert
+        let nft_contract = abi(NFT, nft.value);
+        require(nft_contract.admin() == Identity::ContractId(contract_id()), CreationError::FundraiserNotNftAdmin);
+
+        // Every tier sharing the contract, in any campaign, must be able to mint its full quantity
+        let reserved = storage.nft_reserved.get(nft);
+        require(quantity <= nft_contract.max_supply() - reserved, CreationError::NftSupplyExceeded);
+        storage.nft_reserved.insert(nft, reserved + quantity);
+
+        let reward_tier = RewardTier {
+            minimum_pledge, nft, quantity, selected: 0,
+        };
+
+        let tier = storage.reward_tier_count.get(id) + 1;
+        storage.reward_tier_count.insert(id, tier);
+        storage.reward_tiers.insert((id, tier), reward_tier);
+
+        log(FundraiserEvent::AddedRewardTier(AddedRewardTierEvent {
+            id, reward_tier, tier
+        }));
     }
 
-    /// Returns information about the specified campaign for the campaign author
+    /// Selects the reward tier that the user wishes to receive a receipt for upon a successful claim
+    ///
+    /// A user can only hold a single tier per campaign therefore selecting a new tier releases the
+    /// previously selected tier for other backers.
     ///
     /// # Arguments
     ///
-    /// * `id` - Unique identifier which is a number starting from 1...storage.user_campaign_count
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `tier` - Number from 1...reward_tier_count identifying the tier
     ///
     /// # Reverts
     ///
-    /// * When the `id` is either 0 or greater than the total number of campaigns created by the author
-    #[storage(read)]fn campaign(id: u64, user: Identity) -> Campaign {
-        // Validate the ID to ensure that the user has created the campaign
-        validate_id(id, storage.user_campaign_count.get(user));
-        storage.campaign_history.get((user, id))
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the `tier` is either 0 or greater than the number of tiers in the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the user has pledged less than the minimum of the tier
+    /// * When every reward in the tier has been selected
+    #[storage(read, write)]fn select_reward_tier(id: u64, tier: u64) {
+        // User cannot interact with a non-existent campaign or tier
+        validate_id(id, storage.total_campaigns);
+        validate_id(tier, storage.reward_tier_count.get(id));
+
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Selections are locked once pledging has ended
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // The user must have pledged enough to qualify for the tier
+        let user = msg_sender().unwrap();
+        let pledge_history_index = storage.pledge_history_index.get((user, id));
+        require(pledge_history_index != 0, UserError::UserHasNotPledged);
+
+        let mut reward_tier = storage.reward_tiers.get((id, tier));
+        require(reward_tier.minimum_pledge <= storage.pledge_history.get((user, pledge_history_index)).amount, UserError::InsufficientPledge);
+
+        // Release the previous selection before taking a place in the new tier so that reselecting
+        // the same tier does not count against its quantity
+        remove_reward_selection(id, user);
+        reward_tier = storage.reward_tiers.get((id, tier));
+
+        require(reward_tier.selected < reward_tier.quantity, CampaignError::RewardTierSoldOut);
+
+        reward_tier.selected = reward_tier.selected + 1;
+        storage.reward_tiers.insert((id, tier), reward_tier);
+        storage.reward_backers.insert((id, tier, reward_tier.selected), user);
+        storage.reward_selection.insert((user, id), RewardSelection {
+            position: reward_tier.selected,
+            tier,
+        });
+
+        log(FundraiserEvent::SelectedRewardTier(SelectedRewardTierEvent {
+            id, tier, user
+        }));
     }
 
-    /// Returns the number of campaigns that the user has pledged to
+    /// Mints the receipt of the reward tier that the user selected once the campaign is claimed
+    ///
+    /// Each backer mints their own receipt so that the cost of minting is not bounded by the
+    /// number of backers in a single transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
     ///
     /// # Reverts
     ///
-    /// * When an AuthError is generated
-    #[storage(read)]fn pledge_count(user: Identity) -> u64 {
-        storage.pledge_count.get(user)
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the campaign has not been claimed
+    /// * When the user has not selected a reward tier
+    /// * When the user has already claimed their reward
+    /// * When the fundraiser is unable to mint a receipt from the NFT contract of the tier
+    #[storage(read, write)]fn claim_reward(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Receipts are only minted once the author has claimed a successful campaign
+        require(storage.campaign_info.get(id).status == CampaignStatus::Claimed, CampaignError::CampaignNotClaimed);
+
+        // The user must hold a selection which has not been minted yet
+        let user = msg_sender().unwrap();
+        let selection = storage.reward_selection.get((user, id));
+        require(selection.tier != 0, UserError::NoRewardSelected);
+        require(!storage.reward_claimed.get((user, id)), UserError::RewardAlreadyClaimed);
+
+        // Mark the reward as claimed before calling the NFT contract
+        storage.reward_claimed.insert((user, id), true);
+
+        let reward_tier = storage.reward_tiers.get((id, selection.tier));
+        abi(NFT, reward_tier.nft.value).mint(1, user);
+
+        log(FundraiserEvent::ClaimedReward(ClaimedRewardEvent {
+            id, tier: selection.tier, user
+        }));
     }
 
-    /// Returns information about the specified pledge for the user
+    /// Splits the release of the total pledge into tranches which must be approved by the backers
+    ///
+    /// Once claimed, the author proposes each tranche which the backers vote on with a weight equal
+    /// to the amount that they have pledged. If a tranche is rejected, or the author does not
+    /// propose the next tranche within `PROPOSAL_PERIODS` voting periods, then the backers are able
+    /// to reclaim their share of the unreleased remainder.
     ///
     /// # Arguments
     ///
-    /// * `pledge_history_index` - Unique identifier which is a number starting from 1...storage.pledge_count
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `tranches` - The number of equal parts that the total pledge is split into
+    /// * `voting_period` - The number of blocks that backers have to vote on each tranche
     ///
     /// # Reverts
     ///
-    /// * When the `pledge_history_index` is either 0 or greater than the total number of pledges made by the user
-    #[storage(read)]fn pledged(pledge_history_index: u64, user: Identity) -> Pledge {
-        // Validate the ID to ensure that the user has pledged
-        validate_id(pledge_history_index, storage.pledge_count.get(user));
-        storage.pledge_history.get((user, pledge_history_index))
-    }
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the total pledge is not 0
+    /// * When the campaign has a sponsor
+    /// * When the `tranches` is 0
+    /// * When the `voting_period` is 0
+    /// * When the `voting_period` is greater than the maximum voting period
+    #[storage(read, write)]fn set_milestones(id: u64, tranches: u64, voting_period: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
 
-    /// Returns the number of unique assets that have added across all campaigns
-    #[storage(read)]fn asset_count() -> u64 {
-        storage.asset_count
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can set the milestones
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // Users and sponsors who have committed funds must not have the conditions changed
+        // underneath them
+        require(campaign_info.total_pledge == 0, CampaignError::CampaignHasPledges);
+        require(storage.sponsor_pools.get(id).cap == 0, CampaignError::AlreadySponsored);
+
+        require(0 < tranches, CreationError::TranchesCannotBeZero);
+        require(0 < voting_period, CreationError::VotingPeriodCannotBeZero);
+        require(voting_period <= MAX_VOTING_PERIOD, CreationError::VotingPeriodTooLong);
+
+        storage.milestones.insert(id, MilestoneInfo {
+            failed: false,
+            no_votes: 0,
+            proposal_deadline: 0,
+            released: 0,
+            released_amount: 0,
+            tranches,
+            vote_deadline: 0,
+            voting: false,
+            voting_period,
+            yes_votes: 0,
+        });
+
+        log(FundraiserEvent::SetMilestones(SetMilestonesEvent {
+            id, tranches, voting_period
+        }));
     }
 
-    /// Returns information about the specificed asset, specifically if it has been added and the
-    /// pledged amount
+    /// Opens a vote on the release of the next tranche to the beneficiaries
     ///
     /// # Arguments
     ///
-    /// * `asset` - Uniquie identifier that identifies the asset
-    #[storage(read)]fn asset_info_by_id(asset: ContractId) -> AssetInfo {
-        storage.asset_info.get(asset)
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the campaign does not use milestones
+    /// * When the campaign has not been claimed
+    /// * When the backers have rejected a previous tranche
+    /// * When a vote is already in progress
+    /// * When every tranche has been released
+    #[storage(read, write)]fn propose_tranche(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+        let mut milestone_info = storage.milestones.get(id);
+
+        // Only the creator (author) of the campaign can ask for funds
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        require(milestone_info.tranches != 0, CampaignError::MilestonesNotEnabled);
+
+        // Tranches can only be released from a campaign that has succeeded and been claimed
+        require(campaign_info.status == CampaignStatus::Claimed, CampaignError::CampaignNotClaimed);
+
+        require(!milestone_info.failed, CampaignError::MilestonesFailed);
+        require(!milestone_info.voting, CampaignError::TrancheVoteInProgress);
+        require(milestone_info.released < milestone_info.tranches, CampaignError::AllTranchesReleased);
+
+        // Start a fresh vote for the next tranche
+        milestone_info.no_votes = 0;
+        milestone_info.vote_deadline = height() + milestone_info.voting_period;
+        milestone_info.voting = true;
+        milestone_info.yes_votes = 0;
+
+        storage.milestones.insert(id, milestone_info);
+
+        log(FundraiserEvent::ProposedTranche(ProposedTrancheEvent {
+            id, tranche: milestone_info.released + 1, vote_deadline: milestone_info.vote_deadline
+        }));
     }
 
-    /// Returns information about the specificed asset, specifically if it has been added and the
-    /// pledged amount
-    ///
-    /// The user interface will not know all possible assets that the contract contains therefore
-    /// this helper method allows the interface to iterate over the asset_count to discover all assets
+    /// Votes on the release of the proposed tranche with a weight equal to the amount pledged
     ///
     /// # Arguments
     ///
-    /// * `index` - Number from 1...asset_count
-    #[storage(read)]fn asset_info_by_count(index: u64) -> AssetInfo {
-        storage.asset_info.get(storage.asset_index.get(index))
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `approve` - Whether the user wishes for the tranche to be released
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When there is no vote in progress
+    /// * When the voting period has ended
+    /// * When the user has not pledged to the campaign
+    /// * When the user has already voted on the tranche
+    #[storage(read, write)]fn vote_tranche(id: u64, approve: bool) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let mut milestone_info = storage.milestones.get(id);
+
+        require(milestone_info.voting, CampaignError::TrancheVoteNotInProgress);
+        require(height() < milestone_info.vote_deadline, CampaignError::TrancheVoteEnded);
+
+        // Only backers have a say in the release of their pledges
+        let user = msg_sender().unwrap();
+        let pledge_history_index = storage.pledge_history_index.get((user, id));
+        require(pledge_history_index != 0, UserError::UserHasNotPledged);
+
+        let weight = storage.pledge_history.get((user, pledge_history_index)).amount;
+        require(0 < weight, UserError::UserHasNotPledged);
+
+        // Tranches are numbered from 1 therefore 0 is the sentinel for never having voted
+        let tranche = milestone_info.released + 1;
+        require(storage.tranche_votes.get((user, id)) != tranche, UserError::AlreadyVoted);
+
+        if approve {
+            milestone_info.yes_votes = milestone_info.yes_votes + weight;
+        } else {
+            milestone_info.no_votes = milestone_info.no_votes + weight;
+        }
+
+        storage.milestones.insert(id, milestone_info);
+        storage.tranche_votes.insert((user, id), tranche);
+
+        log(FundraiserEvent::VotedTranche(VotedTrancheEvent {
+            approve, id, tranche, user, weight
+        }));
+    }
+
+    /// Resolves the vote on the proposed tranche once the voting period has ended
+    ///
+    /// If more weight has voted for the release than against it then the tranche is sent to the
+    /// beneficiaries. Otherwise the milestones are marked as failed and the backers are able to
+    /// reclaim the unreleased remainder.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When there is no vote in progress
+    /// * When the voting period has not ended
+    #[storage(read, write)]fn release_tranche(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+        let mut milestone_info = storage.milestones.get(id);
+
+        require(milestone_info.voting, CampaignError::TrancheVoteNotInProgress);
+        require(milestone_info.vote_deadline <= height(), CampaignError::TrancheVoteNotEnded);
+
+        let tranche = milestone_info.released + 1;
+        milestone_info.voting = false;
+
+        if milestone_info.no_votes < milestone_info.yes_votes {
+            // The final tranche also releases any remainder left over from the division
+            let amount = if tranche == milestone_info.tranches {
+                campaign_info.total_pledge - milestone_info.released_amount
+            } else {
+                campaign_info.total_pledge / milestone_info.tranches
+            };
+
+            milestone_info.proposal_deadline = height() + milestone_info.voting_period * PROPOSAL_PERIODS;
+            milestone_info.released = tranche;
+            milestone_info.released_amount = milestone_info.released_amount + amount;
+            storage.milestones.insert(id, milestone_info);
+
+            // The platform fee is deducted from each released tranche
+            transfer_to_beneficiaries(deduct_fee(amount, campaign_info.asset), campaign_info.asset, campaign_info.beneficiaries);
+
+            log(FundraiserEvent::ReleasedTranche(ReleasedTrancheEvent {
+                amount, id, tranche
+            }));
+        } else {
+            milestone_info.failed = true;
+            storage.milestones.insert(id, milestone_info);
+
+            log(FundraiserEvent::RejectedTranche(RejectedTrancheEvent {
+                id, tranche
+            }));
+        }
+    }
+
+    /// Marks the milestones as failed when the author has not proposed the next tranche in time
+    ///
+    /// Anyone can call this function so that the unreleased remainder is not locked in the contract
+    /// by an author who stops proposing tranches. The backers are then able to reclaim their share.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the campaign does not use milestones
+    /// * When the campaign has not been claimed
+    /// * When the backers have rejected a previous tranche
+    /// * When a vote is in progress
+    /// * When every tranche has been released
+    /// * When the proposal deadline has not been reached
+    #[storage(read, write)]fn abandon_milestones(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+        let mut milestone_info = storage.milestones.get(id);
+
+        require(milestone_info.tranches != 0, CampaignError::MilestonesNotEnabled);
+        require(campaign_info.status == CampaignStatus::Claimed, CampaignError::CampaignNotClaimed);
+        require(!milestone_info.failed, CampaignError::MilestonesFailed);
+
+        // A proposed tranche is resolved through the vote instead
+        require(!milestone_info.voting, CampaignError::TrancheVoteInProgress);
+        require(milestone_info.released < milestone_info.tranches, CampaignError::AllTranchesReleased);
+
+        // The author has until the deadline to propose the next tranche
+        require(milestone_info.proposal_deadline <= height(), CampaignError::ProposalDeadlineNotReached);
+
+        milestone_info.failed = true;
+        storage.milestones.insert(id, milestone_info);
+
+        log(FundraiserEvent::AbandonedMilestones(AbandonedMilestonesEvent {
+            id, tranche: milestone_info.released + 1
+        }));
+    }
+
+    /// Returns the user's share of the unreleased remainder after the milestones have failed
+    ///
+    /// The share is proportional to the amount the user has pledged relative to the total pledge.
+    /// The sponsor of the campaign also reclaims the share of the pledges that their pool matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the milestones have not failed
+    /// * When the user has neither pledged to nor sponsored the campaign or has already reclaimed
+    #[storage(read, write)]fn reclaim_remainder(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+        let milestone_info = storage.milestones.get(id);
+
+        require(milestone_info.failed, CampaignError::MilestonesNotFailed);
+
+        let user = msg_sender().unwrap();
+
+        // The share of a user is made up of their own pledge and, for the sponsor, the part of the
+        // total pledge that their pool has matched
+        let mut shares = 0;
+
+        let pledge_history_index = storage.pledge_history_index.get((user, id));
+        if pledge_history_index != 0 {
+            let mut pledge = storage.pledge_history.get((user, pledge_history_index));
+            shares = pledge.amount;
+
+            // Zero the pledge to prevent the user from reclaiming more than once
+            pledge.amount = 0;
+            storage.pledge_history.insert((user, pledge_history_index), pledge);
+        }
+
+        let mut pool = storage.sponsor_pools.get(id);
+        if pool.cap != 0 && pool.sponsor == user {
+            shares = shares + pool.matched;
+
+            // Zero the match to prevent the sponsor from reclaiming more than once
+            pool.matched = 0;
+            storage.sponsor_pools.insert(id, pool);
+        }
+
+        require(0 < shares, UserError::UserHasNotPledged);
+
+        // The total pledge is left untouched so that every backer receives the same proportion
+        let amount = mul_div(shares, campaign_info.total_pledge - milestone_info.released_amount, campaign_info.total_pledge);
+
+        transfer(amount, campaign_info.asset, user);
+
+        log(FundraiserEvent::Reclaimed(ReclaimedEvent {
+            amount, id, user
+        }));
+    }
+
+    /// Returns the pledges of a range of backers of a cancelled or failed campaign
+    ///
+    /// Anyone can call this function so that backers who do not unpledge themselves are not left
+    /// with their pledge stranded in the contract. Backers who have already unpledged are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `start` - Number from 1...backer_count of the first backer to refund
+    /// * `count` - The number of backers to refund, capped at the last backer
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the `start` is either 0 or greater than the number of backers of the campaign
+    /// * When the campaign has not been cancelled and the deadline has not been reached
+    /// * When the campaign has not been cancelled and the `target_amount` or the quote target has been reached
+    #[storage(read, write)]fn refund_batch(id: u64, start: u64, count: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // The first backer must exist otherwise there is nothing to refund
+        let backer_count = storage.backer_count.get(id);
+        validate_id(start, backer_count);
+
+        // Retrieve the campaign in order to check its data / update it
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // A campaign that has not been cancelled must have ended without reaching its target
+        if campaign_info.status != CampaignStatus::Cancelled {
+            require(campaign_info.deadline <= height(), CampaignError::DeadlineNotReached);
+            require(effective_status(id, campaign_info) == CampaignStatus::Failed, CampaignError::TargetReached);
+        }
+
+        // Do not walk past the last backer, comparing the remaining backers against the `count` so
+        // that a large `count` cannot overflow
+        let end = if backer_count - start < count {
+            backer_count + 1
+        } else {
+            start + count
+        };
+
+        let mut asset_info = storage.asset_info.get(campaign_info.asset);
+
+        let mut position = start;
+        while position < end {
+            let user = storage.backers.get((id, position));
+            let pledge_history_index = storage.pledge_history_index.get((user, id));
+            let mut pledge = storage.pledge_history.get((user, pledge_history_index));
+
+            // Backers who have unpledged everything have nothing left to refund
+            if pledge.amount != 0 {
+                let amount = pledge.amount;
+
+                // Clear the pledge so that the backer cannot be refunded twice
+                pledge.amount = 0;
+                storage.pledge_history.insert((user, pledge_history_index), pledge);
+
+                // The backer no longer qualifies for any reward tier
+                remove_reward_selection(id, user);
+
+                // Track the removal of the pledge from the campaign and the asset
+                campaign_info.total_pledge = campaign_info.total_pledge - amount;
+                asset_info.amount = asset_info.amount - amount;
+
+                transfer(amount, campaign_info.asset, user);
+
+                log(FundraiserEvent::Refunded(RefundedEvent {
+                    amount, id, user
+                }));
+            }
+
+            position += 1;
+        }
+
+        // The total pledge of a campaign that was cancelled before its deadline may have fallen below
+        // the stretch goals that it had reached
+        campaign_info.stretch_goals_reached = update_stretch_goals(id, campaign_info);
+
+        // Update the campaign and asset state with the refunded amounts removed
+        storage.campaign_info.insert(id, campaign_info);
+        storage.asset_info.insert(campaign_info.asset, asset_info);
+    }
+
+    /// Provides a pool of the campaign asset which matches the pledges of users 1:1 until it is
+    /// exhausted
+    ///
+    /// Pledges made before the pool is provided are not matched. The pool, minus any matched
+    /// pledges on a successful claim, is returned to the sponsor when the campaign is cancelled,
+    /// fails or is claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the deadline has been reached
+    /// * When the campaign has been cancelled
+    /// * When the sponsor sends a different asset to the one specified in the campaign
+    /// * When the sponsor sends 0
+    /// * When the campaign already has a sponsor
+    #[storage(read, write)]fn sponsor(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Retrieve the campaign in order to check its data
+        let campaign_info = storage.campaign_info.get(id);
+
+        // The pool can only be provided while users are able to pledge
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // The pool matches pledges therefore it must be the asset that the campaign accepts
+        require(campaign_info.asset == msg_asset_id(), UserError::IncorrectAssetSent);
+        require(0 < msg_amount(), UserError::AmountCannotBeZero);
+
+        // A campaign has a single sponsor
+        require(storage.sponsor_pools.get(id).cap == 0, CampaignError::AlreadySponsored);
+
+        let sponsor = msg_sender().unwrap();
+        storage.sponsor_pools.insert(id, SponsorPool {
+            cap: msg_amount(),
+            matched: 0,
+            settled: false,
+            sponsor,
+        });
+
+        log(FundraiserEvent::Sponsored(SponsoredEvent {
+            amount: msg_amount(), id, sponsor
+        }));
+    }
+
+    /// Returns the entire sponsor pool to the sponsor of a campaign that has failed to reach its
+    /// target amount
+    ///
+    /// Anyone can call this function. The matched part of the pool is removed from the total pledge.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the campaign does not have a sponsor
+    /// * When the sponsor pool has already been returned
+    /// * When the deadline has not been reached
+    /// * When the `target_amount` or the quote target has been reached
+    #[storage(read, write)]fn refund_sponsor(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let pool = storage.sponsor_pools.get(id);
+        require(pool.cap != 0, CampaignError::NotSponsored);
+
+        // Cancelling or claiming the campaign also returns the pool
+        require(!pool.settled, CampaignError::SponsorPoolSettled);
+
+        // Retrieve the campaign in order to check its data / update it
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // The campaign must have ended without reaching its target
+        require(campaign_info.deadline <= height(), CampaignError::DeadlineNotReached);
+        require(effective_status(id, campaign_info) == CampaignStatus::Failed, CampaignError::TargetReached);
+
+        // The matched part of the pool no longer counts towards the total pledge. The deadline has
+        // been reached therefore the stretch goals that were reached are left as they are
+        campaign_info.total_pledge = campaign_info.total_pledge - pool.matched;
+        storage.campaign_info.insert(id, campaign_info);
+
+        settle_sponsor_pool(id, campaign_info.asset, pool.cap);
+    }
+
+    /// Transfers the fees that have accrued in the `asset` to the fee recipient
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - The asset in which the fees have accrued
+    ///
+    /// # Reverts
+    ///
+    /// * When the user is not the fee recipient
+    /// * When no fees have accrued in the `asset`
+    #[storage(read, write)]fn withdraw_fees(asset: ContractId) {
+        // Only the fee recipient set upon initialization can withdraw the fees
+        let recipient = msg_sender().unwrap();
+        require(recipient == storage.fee_recipient, UserError::UnauthorizedUser);
+
+        let amount = storage.fees.get(asset);
+        require(amount != 0, UserError::NoFeesToWithdraw);
+
+        // Reset the fees before transferring to prevent withdrawing them twice
+        storage.fees.insert(asset, 0);
+        transfer(amount, asset, recipient);
+
+        log(FundraiserEvent::WithdrawnFees(WithdrawnFeesEvent {
+            amount, asset, recipient
+        }));
+    }
+
+    /// Restricts pledging to the users on the allowlist of the campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `private` - Whether only allowlisted users are able to pledge
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    #[storage(read, write)]fn set_private(id: u64, private: bool) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can restrict who pledges
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        // Restricting pledges only makes sense while the campaign accepts them
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        storage.private_campaigns.insert(id, private);
+
+        log(FundraiserEvent::SetPrivate(SetPrivateEvent {
+            id, private
+        }));
+    }
+
+    /// Allows the `user` to pledge to a private campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who is approved by the author
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    #[storage(read, write)]fn add_to_allowlist(id: u64, user: Identity) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Only the creator (author) of the campaign can manage the allowlist
+        require(storage.campaign_info.get(id).author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        storage.allowlist.insert((id, user), true);
+
+        log(FundraiserEvent::Allowlisted(AllowlistedEvent {
+            id, user
+        }));
+    }
+
+    /// Prevents the `user` from making further pledges to a private campaign
+    ///
+    /// Existing pledges are unaffected and can still be unpledged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who is no longer approved by the author
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    #[storage(read, write)]fn remove_from_allowlist(id: u64, user: Identity) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Only the creator (author) of the campaign can manage the allowlist
+        require(storage.campaign_info.get(id).author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        storage.allowlist.insert((id, user), false);
+
+        log(FundraiserEvent::RemovedFromAllowlist(RemovedFromAllowlistEvent {
+            id, user
+        }));
+    }
+
+    /// Pledges the forwarded amount on behalf of another user
+    ///
+    /// The pledge belongs to the `user` as if they had pledged it themselves therefore only they
+    /// are able to unpledge it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who receives the pledge
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the deadline has been reached
+    /// * When the sender pledges a different asset to the one specified in the campaign
+    /// * When the sender pledges 0
+    /// * When the campaign has been cancelled
+    /// * When the campaign is private and the `user` is not on its allowlist
+    #[storage(read, write)]fn pledge_for(id: u64, user: Identity) {
+        receive_pledge(id, user);
+
+        log(FundraiserEvent::PledgedFor(PledgedForEvent {
+            amount: msg_amount(), id, sender: msg_sender().unwrap(), user
+        }));
+    }
+
+    /// Moves an `amount` of the pledge of the user to another user
+    ///
+    /// The recipient takes over the right to unpledge or be refunded that amount and any part of
+    /// the sponsor match which is no longer covered by the remaining pledge of the sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `to` - The user who receives the pledge
+    /// * `amount` - The amount of the pledge to transfer
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the `amount` is 0
+    /// * When the user transfers to themselves
+    /// * When the campaign has been claimed
+    /// * When a tranche vote is in progress
+    /// * When the campaign is private and `to` is not on its allowlist
+    /// * When the user has not pledged to the campaign
+    /// * When the user has pledged less than the `amount`
+    ///
+    /// If the remaining pledge falls below the minimum of the selected reward tier then the
+    /// selection is removed
+    #[storage(read, write)]fn transfer_pledge(id: u64, to: Identity, amount: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        require(amount != 0, UserError::AmountCannotBeZero);
+
+        let from = msg_sender().unwrap();
+        require(from != to, UserError::CannotTransferToSelf);
+
+        // Once the author has claimed there is nothing left to unpledge or refund
+        require(storage.campaign_info.get(id).status != CampaignStatus::Claimed, UserError::AlreadyClaimed);
+
+        // The weight of a vote is the pledge at the time of voting therefore moving it during a
+        // vote would allow the same amount to be counted twice
+        require(!storage.milestones.get(id).voting, CampaignError::TrancheVoteInProgress);
+
+        // A private campaign only accepts pledges from the users that the author has approved
+        require(!storage.private_campaigns.get(id) || storage.allowlist.get((id, to)), UserError::NotAllowlisted);
+
+        // Check if the user has pledged to the campaign they are attempting to transfer from
+        let pledge_history_index = storage.pledge_history_index.get((from, id));
+        require(pledge_history_index != 0, UserError::UserHasNotPledged);
+
+        let mut pledge = storage.pledge_history.get((from, pledge_history_index));
+        require(amount <= pledge.amount, UserError::InsufficientPledge);
+
+        // Update the amount that the sender has pledged
+        pledge.amount = pledge.amount - amount;
+        storage.pledge_history.insert((from, pledge_history_index), pledge);
+
+        // The transferred amount carries no vote for an extension with it
+        reduce_extension_vote(id, from, pledge.amount);
+
+        // The sender only keeps the match that is covered by their remaining pledge
+        let from_matched = storage.sponsor_matched.get((from, id));
+        if pledge.amount < from_matched {
+            storage.sponsor_matched.insert((from, id), pledge.amount);
+            storage.sponsor_matched.insert((to, id), storage.sponsor_matched.get((to, id)) + from_matched - pledge.amount);
+        }
+
+        // The sender may no longer qualify for the reward tier that they have selected
+        let selection = storage.reward_selection.get((from, id));
+        if selection.tier != 0 && pledge.amount < storage.reward_tiers.get((id, selection.tier)).minimum_pledge {
+            remove_reward_selection(id, from);
+        }
+
+        // Record the amount against the recipient so that they can unpledge it
+        add_to_pledge(id, to, amount);
+
+        log(FundraiserEvent::TransferredPledge(TransferredPledgeEvent {
+            amount, from, id, to
+        }));
+    }
+
+    /// Creates a recurring pledge to a campaign which is funded by the forwarded amount
+    ///
+    /// The author of the campaign can pull the `amount` once every `period` blocks until the
+    /// balance is exhausted or the backer cancels the subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `amount` - The amount that the author can pull each period
+    /// * `period` - The number of blocks between each pull
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the campaign has been cancelled
+    /// * When the user sends a different asset to the one specified in the campaign
+    /// * When the user sends 0
+    /// * When the `amount` is 0
+    /// * When the `period` is 0
+    #[storage(read, write)]fn subscribe(id: u64, amount: u64, period: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        // Retrieve the campaign in order to check its data
+        let campaign_info = storage.campaign_info.get(id);
+
+        // The author has stopped accepting support for the campaign
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // The balance is paid out to the beneficiaries therefore it must be the asset of the campaign
+        require(campaign_info.asset == msg_asset_id(), UserError::IncorrectAssetSent);
+        require(0 < msg_amount(), UserError::AmountCannotBeZero);
+
+        require(0 < amount, UserError::AmountCannotBeZero);
+        require(0 < period, CreationError::PeriodCannotBeZero);
+
+        let user = msg_sender().unwrap();
+        let subscription_id = storage.subscription_count + 1;
+
+        // The first amount can be pulled straight away
+        storage.subscriptions.insert(subscription_id, Subscription {
+            amount,
+            backer: user,
+            balance: msg_amount(),
+            cancelled: false,
+            id,
+            next_pull: height(),
+            period,
+        });
+        storage.subscription_count = subscription_id;
+
+        log(FundraiserEvent::Subscribed(SubscribedEvent {
+            amount, balance: msg_amount(), id, period, subscription_id, user
+        }));
+    }
+
+    /// Transfers the amount of the current period from a subscription to the beneficiaries
+    ///
+    /// If the balance is lower than the amount then the remaining balance is pulled.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - Unique subscription identifier which is a number from the storage.subscription_count range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `subscription_id` is either 0 or greater than the total number of subscriptions created
+    /// * When the user is not the author of the campaign
+    /// * When the campaign has been cancelled
+    /// * When the subscription has been cancelled
+    /// * When the balance has been exhausted
+    /// * When the period since the last pull has not elapsed
+    #[storage(read, write)]fn pull_subscription(subscription_id: u64) {
+        // User cannot interact with a non-existent subscription
+        validate_id(subscription_id, storage.subscription_count);
+
+        let mut subscription = storage.subscriptions.get(subscription_id);
+        let campaign_info = storage.campaign_info.get(subscription.id);
+
+        // Only the creator (author) of the campaign can pull from the subscription
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        // The backer of a cancelled campaign should only be able to withdraw their balance
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        require(!subscription.cancelled, SubscriptionError::SubscriptionCancelled);
+        require(subscription.balance != 0, SubscriptionError::BalanceExhausted);
+        require(subscription.next_pull <= height(), SubscriptionError::PeriodNotElapsed);
+
+        let mut amount = subscription.amount;
+        if subscription.balance < amount {
+            amount = subscription.balance;
+        }
+
+        // Periods that have been missed are not pulled retroactively
+        subscription.balance = subscription.balance - amount;
+        subscription.next_pull = height() + subscription.period;
+        storage.subscriptions.insert(subscription_id, subscription);
+
+        let payout = deduct_fee(amount, campaign_info.asset);
+        transfer_to_beneficiaries(payout, campaign_info.asset, campaign_info.beneficiaries);
+
+        log(FundraiserEvent::PulledSubscription(PulledSubscriptionEvent {
+            amount, id: subscription.id, subscription_id
+        }));
+    }
+
+    /// Stops a subscription and returns the remaining balance to the backer
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - Unique subscription identifier which is a number from the storage.subscription_count range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `subscription_id` is either 0 or greater than the total number of subscriptions created
+    /// * When the user is not the backer of the subscription
+    /// * When the subscription has already been cancelled
+    #[storage(read, write)]fn cancel_subscription(subscription_id: u64) {
+        // User cannot interact with a non-existent subscription
+        validate_id(subscription_id, storage.subscription_count);
+
+        let mut subscription = storage.subscriptions.get(subscription_id);
+
+        // Only the backer who funded the subscription can cancel it
+        let user = msg_sender().unwrap();
+        require(subscription.backer == user, UserError::UnauthorizedUser);
+
+        require(!subscription.cancelled, SubscriptionError::SubscriptionCancelled);
+
+        let amount = subscription.balance;
+
+        // Mark the subscription as cancelled before transferring to prevent withdrawing twice
+        subscription.balance = 0;
+        subscription.cancelled = true;
+        storage.subscriptions.insert(subscription_id, subscription);
+
+        if amount != 0 {
+            transfer(amount, storage.campaign_info.get(subscription.id).asset, user);
+        }
+
+        log(FundraiserEvent::Unsubscribed(UnsubscribedEvent {
+            amount, subscription_id, user
+        }));
+    }
+
+    /// Asks the backers of a campaign to approve pushing the deadline forward
+    ///
+    /// Each campaign can only request a single extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `extension` - The number of blocks to add to the deadline
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When an extension has already been requested
+    /// * When the `extension` is 0
+    /// * When the `extension` is greater than the maximum extension
+    #[storage(read, write)]fn request_extension(id: u64, extension: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can request more time
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // The deadline can only be pushed forward once
+        require(!storage.extensions.get(id).requested, CampaignError::ExtensionAlreadyRequested);
+
+        require(0 < extension, CreationError::ExtensionCannotBeZero);
+        require(extension <= MAX_EXTENSION, CreationError::ExtensionTooLong);
+
+        storage.extensions.insert(id, ExtensionInfo {
+            approved: false,
+            extension,
+            requested: true,
+            yes_votes: 0,
+        });
+
+        log(FundraiserEvent::RequestedExtension(RequestedExtensionEvent {
+            extension, id
+        }));
+    }
+
+    /// Approves the requested extension with a weight equal to the amount pledged
+    ///
+    /// Once the approving weight is more than half of the amount pledged by users the deadline is
+    /// extended.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When an extension has not been requested
+    /// * When the extension has already been approved
+    /// * When the user has not pledged to the campaign
+    /// * When the user has already voted on the extension
+    #[storage(read, write)]fn vote_extension(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // The vote must conclude before the campaign ends
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        let mut extension_info = storage.extensions.get(id);
+        require(extension_info.requested, CampaignError::ExtensionNotRequested);
+        require(!extension_info.approved, CampaignError::ExtensionAlreadyApproved);
+
+        // Only backers have a say in how long their pledges are locked for
+        let user = msg_sender().unwrap();
+        let pledge_history_index = storage.pledge_history_index.get((user, id));
+        require(pledge_history_index != 0, UserError::UserHasNotPledged);
+
+        let weight = storage.pledge_history.get((user, pledge_history_index)).amount;
+        require(0 < weight, UserError::UserHasNotPledged);
+
+        // 0 is the sentinel for never having voted
+        require(storage.extension_votes.get((user, id)) == 0, UserError::AlreadyVoted);
+
+        extension_info.yes_votes = extension_info.yes_votes + weight;
+        storage.extension_votes.insert((user, id), weight);
+
+        log(FundraiserEvent::VotedExtension(VotedExtensionEvent {
+            id, user, weight
+        }));
+
+        // The sponsor match is not pledged by users therefore it does not count towards the majority
+        let pledged = campaign_info.total_pledge - storage.sponsor_pools.get(id).matched;
+
+        // More than half of the pledges must have voted for the extension. The votes never exceed
+        // the pledges therefore comparing the remainder avoids doubling the votes
+        if pledged - extension_info.yes_votes < extension_info.yes_votes {
+            extension_info.approved = true;
+
+            campaign_info.deadline = campaign_info.deadline + extension_info.extension;
+            storage.campaign_info.insert(id, campaign_info);
+
+            log(FundraiserEvent::ExtendedDeadline(ExtendedDeadlineEvent {
+                deadline: campaign_info.deadline, id
+            }));
+        }
+
+        storage.extensions.insert(id, extension_info);
+    }
+
+    /// Adds a stretch goal which is an additional target above the previous goal of a campaign
+    ///
+    /// A campaign can have up to 4 stretch goals which are reached in order as the total pledge grows.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `target` - The total pledge at which the stretch goal is reached
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the campaign already has 4 stretch goals
+    /// * When the `target` is not greater than the `target_amount` and the previous stretch goal
+    #[storage(read, write)]fn add_stretch_goal(id: u64, target: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can announce stretch goals
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        // Stretch goals can only be added while users are able to pledge
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // Find the first unused slot, 0 is the sentinel for an unused stretch goal
+        let goals = campaign_info.stretch_goals;
+        let mut goal = 0;
+        let mut previous = campaign_info.target_amount;
+        let mut searching = true;
+        while searching && goal < 4 {
+            if goals[goal] == 0 {
+                searching = false;
+            } else {
+                previous = goals[goal];
+                goal += 1;
+            }
+        }
+
+        require(goal < 4, CampaignError::StretchGoalLimitReached);
+
+        // Stretch goals are ordered so that each one is reached after the previous
+        require(previous < target, CreationError::StretchGoalMustExceedPreviousGoal);
+
+        campaign_info.stretch_goals = [
+            if goal == 0 { target } else { goals[0] },
+            if goal == 1 { target } else { goals[1] },
+            if goal == 2 { target } else { goals[2] },
+            if goal == 3 { target } else { goals[3] },
+        ];
+
+        log(FundraiserEvent::AddedStretchGoal(AddedStretchGoalEvent {
+            goal: goal + 1, id, target
+        }));
+
+        // The total pledge may already exceed the new stretch goal
+        campaign_info.stretch_goals_reached = update_stretch_goals(id, campaign_info);
+        storage.campaign_info.insert(id, campaign_info);
+    }
+
+    /// Denominates the target of a campaign in a quote unit which is valued by a price oracle
+    ///
+    /// Once the deadline has been reached the total pledge is converted at the price reported by
+    /// the `oracle` and compared against the `target` instead of the `target_amount`. The price is
+    /// recorded once through `record_price` so that claims and refunds agree on the outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `oracle` - The contract which reports the price of the campaign asset in the quote unit
+    /// * `target` - The value in the quote unit required to deem the campaign a success
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the user is not the author of the campaign
+    /// * When the deadline has been surpassed
+    /// * When the campaign has been cancelled
+    /// * When the total pledge is not 0
+    /// * When the campaign has a sponsor
+    /// * When the `target` is 0
+    #[storage(read, write)]fn set_quote_target(id: u64, oracle: ContractId, target: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+
+        // Only the creator (author) of the campaign can change how it is deemed a success
+        require(campaign_info.author == msg_sender().unwrap(), UserError::UnauthorizedUser);
+
+        require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+        // Users and sponsors who have committed funds must not have the conditions changed
+        // underneath them
+        require(campaign_info.total_pledge == 0, CampaignError::CampaignHasPledges);
+        require(storage.sponsor_pools.get(id).cap == 0, CampaignError::AlreadySponsored);
+
+        require(0 < target, CreationError::TargetAmountCannotBeZero);
+
+        storage.quote_targets.insert(id, QuoteTarget {
+            oracle, price: 0, target
+        });
+
+        log(FundraiserEvent::SetQuoteTarget(SetQuoteTargetEvent {
+            id, oracle, target
+        }));
+    }
+
+    /// Records the price reported by the oracle of a campaign with a quote target
+    ///
+    /// Any user is able to record the price once the deadline has been reached. The price can only
+    /// be recorded once and within `PRICE_RECORDING_PERIOD` blocks of the deadline so that nobody
+    /// is able to wait for a favourable price. A campaign whose price has not been recorded in
+    /// time has not reached its quote target.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the campaign does not have a quote target
+    /// * When the deadline has not been reached
+    /// * When more than `PRICE_RECORDING_PERIOD` blocks have passed since the deadline
+    /// * When the price has already been recorded
+    #[storage(read, write)]fn record_price(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let campaign_info = storage.campaign_info.get(id);
+        let mut quote_target = storage.quote_targets.get(id);
+
+        require(quote_target.target != 0, CampaignError::NoQuoteTarget);
+
+        // The price values the total pledge at the end of the campaign
+        require(campaign_info.deadline <= height(), CampaignError::DeadlineNotReached);
+        require(height() - campaign_info.deadline <= PRICE_RECORDING_PERIOD, CampaignError::PriceRecordingPeriodElapsed);
+        require(quote_target.price == 0, CampaignError::PriceAlreadyRecorded);
+
+        quote_target.price = abi(Oracle, quote_target.oracle.value).price(campaign_info.asset);
+        storage.quote_targets.insert(id, quote_target);
+
+        log(FundraiserEvent::RecordedPrice(RecordedPriceEvent {
+            id, price: quote_target.price
+        }));
+    }
+
+    /// Records whether a campaign which has reached its deadline has succeeded or failed
+    ///
+    /// Any user is able to finalize a campaign. A failed campaign counts towards the track record
+    /// of its author whereas a successful campaign is counted once the author claims it.
+    /// Finalizing a failed campaign returns the NFT supply reserved by its reward tiers
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    /// * When the deadline has not been reached
+    /// * When the campaign has been cancelled
+    /// * When the campaign has been claimed
+    /// * When the campaign has already been finalized
+    #[storage(read, write)]fn finalize(id: u64) {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+
+        let mut campaign_info = storage.campaign_info.get(id);
+
+        // The outcome of a campaign is only known once it has naturally ended
+        require(campaign_info.deadline <= height(), CampaignError::DeadlineNotReached);
+
+        // Only an active campaign can move to a final state
+        require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+        require(campaign_info.status != CampaignStatus::Claimed, UserError::AlreadyClaimed);
+        require(campaign_info.status == CampaignStatus::Active, CampaignError::CampaignFinalized);
+
+        let status = effective_status(id, campaign_info);
+
+        campaign_info.status = status;
+        storage.campaign_info.insert(id, campaign_info);
+
+        // A finalized campaign is no longer listed as active
+        remove_status_campaign(id, CampaignStatus::Active);
+        add_status_campaign(id, status);
+
+        if status == CampaignStatus::Failed {
+            let mut author_stats = storage.author_stats.get(campaign_info.author);
+            author_stats.failed = author_stats.failed + 1;
+            storage.author_stats.insert(campaign_info.author, author_stats);
+
+            // None of the rewards will be minted so the NFT supply that they reserve is returned
+            release_reward_tiers(id, false);
+        }
+
+        log(FundraiserEvent::Finalized(FinalizedEvent {
+            id, status
+        }));
+    }
+
+    /// Returns the total number of campaigns that have been created by all users
+    #[storage(read)]fn total_campaigns() -> u64 {
+        storage.total_campaigns
+    }
+
+    /// Returns information about the specified campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    #[storage(read)]fn campaign_info(id: u64) -> CampaignInfo {
+        // User cannot interact with a non-existent campaign
+        validate_id(id, storage.total_campaigns);
+        storage.campaign_info.get(id)
+    }
+
+    /// Returns the number of campaigns that the user has created
+    #[storage(read)]fn user_campaign_count(user: Identity) -> u64 {
+        storage.user_campaign_count.get(user)
+    }
+
+    /// Returns information about the specified campaign for the campaign author
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique identifier which is a number starting from 1...storage.user_campaign_count
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created by the author
+    #[storage(read)]fn campaign(id: u64, user: Identity) -> Campaign {
+        // Validate the ID to ensure that the user has created the campaign
+        validate_id(id, storage.user_campaign_count.get(user));
+        storage.campaign_history.get((user, id))
+    }
+
+    /// Returns the number of campaigns that the user has pledged to
+    ///
+    /// # Reverts
+    ///
+    /// * When an AuthError is generated
+    #[storage(read)]fn pledge_count(user: Identity) -> u64 {
+        storage.pledge_count.get(user)
+    }
+
+    /// Returns information about the specified pledge for the user
+    ///
+    /// # Arguments
+    ///
+    /// * `pledge_history_index` - Unique identifier which is a number starting from 1...storage.pledge_count
+    ///
+    /// # Reverts
+    ///
+    /// * When the `pledge_history_index` is either 0 or greater than the total number of pledges made by the user
+    #[storage(read)]fn pledged(pledge_history_index: u64, user: Identity) -> Pledge {
+        // Validate the ID to ensure that the user has pledged
+        validate_id(pledge_history_index, storage.pledge_count.get(user));
+        storage.pledge_history.get((user, pledge_history_index))
+    }
+
+    /// Returns the number of unique assets that have added across all campaigns
+    #[storage(read)]fn asset_count() -> u64 {
+        storage.asset_count
+    }
+
+    /// Returns information about the specificed asset, specifically if it has been added and the
+    /// pledged amount
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Uniquie identifier that identifies the asset
+    #[storage(read)]fn asset_info_by_id(asset: ContractId) -> AssetInfo {
+        storage.asset_info.get(asset)
+    }
+
+    /// Returns information about the specificed asset, specifically if it has been added and the
+    /// pledged amount
+    ///
+    /// The user interface will not know all possible assets that the contract contains therefore
+    /// this helper method allows the interface to iterate over the asset_count to discover all assets
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Number from 1...asset_count
+    #[storage(read)]fn asset_info_by_count(index: u64) -> AssetInfo {
+        storage.asset_info.get(storage.asset_index.get(index))
+    }
+
+    /// Returns the number of reward tiers that have been added to a campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn reward_tier_count(id: u64) -> u64 {
+        storage.reward_tier_count.get(id)
+    }
+
+    /// Returns information about the specified reward tier of a campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `tier` - Number from 1...reward_tier_count identifying the tier
+    ///
+    /// # Reverts
+    ///
+    /// * When the `tier` is either 0 or greater than the number of tiers in the campaign
+    #[storage(read)]fn reward_tier(id: u64, tier: u64) -> RewardTier {
+        validate_id(tier, storage.reward_tier_count.get(id));
+        storage.reward_tiers.get((id, tier))
+    }
+
+    /// Returns the reward tier that the user has selected for a campaign
+    ///
+    /// A `tier` of 0 indicates that the user has not selected a tier
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who may have selected a tier
+    #[storage(read)]fn reward_selection(id: u64, user: Identity) -> RewardSelection {
+        storage.reward_selection.get((user, id))
+    }
+
+    /// Returns information about the milestones of a campaign
+    ///
+    /// A `tranches` of 0 indicates that the campaign does not use milestones
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    ///
+    /// # Reverts
+    ///
+    /// * When the `id` is either 0 or greater than the total number of campaigns created
+    #[storage(read)]fn milestone_info(id: u64) -> MilestoneInfo {
+        validate_id(id, storage.total_campaigns);
+        storage.milestones.get(id)
+    }
+
+    /// Returns a page of up to 10 campaigns starting from the campaign with the `start` identifier
+    ///
+    /// Campaigns that do not match the `filter` are returned as empty entries with an `id` of 0
+    /// so that the next page always begins at `start + count`
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Unique campaign identifier of the first campaign in the page
+    /// * `count` - The number of campaigns in the page which is capped at 10
+    /// * `filter` - The state that a campaign must be in to be included in the page
+    #[storage(read)]fn campaigns(start: u64, count: u64, filter: CampaignFilter) -> [CampaignEntry;
+    10] {
+        [
+            campaign_entry(page_index(start, count, 0), filter),
+            campaign_entry(page_index(start, count, 1), filter),
+            campaign_entry(page_index(start, count, 2), filter),
+            campaign_entry(page_index(start, count, 3), filter),
+            campaign_entry(page_index(start, count, 4), filter),
+            campaign_entry(page_index(start, count, 5), filter),
+            campaign_entry(page_index(start, count, 6), filter),
+            campaign_entry(page_index(start, count, 7), filter),
+            campaign_entry(page_index(start, count, 8), filter),
+            campaign_entry(page_index(start, count, 9), filter),
+        ]
+    }
+
+    /// Returns a page of up to 10 campaigns that the user has created
+    ///
+    /// Campaigns that do not match the `filter` are returned as empty entries with an `id` of 0
+    /// so that the next page always begins at `start + count`
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The author of the campaigns
+    /// * `start` - Number from 1...user_campaign_count of the first campaign in the page
+    /// * `count` - The number of campaigns in the page which is capped at 10
+    /// * `filter` - The state that a campaign must be in to be included in the page
+    #[storage(read)]fn user_campaigns(user: Identity, start: u64, count: u64, filter: CampaignFilter) -> [CampaignEntry;
+    10] {
+        [
+            user_campaign_entry(user, page_index(start, count, 0), filter),
+            user_campaign_entry(user, page_index(start, count, 1), filter),
+            user_campaign_entry(user, page_index(start, count, 2), filter),
+            user_campaign_entry(user, page_index(start, count, 3), filter),
+            user_campaign_entry(user, page_index(start, count, 4), filter),
+            user_campaign_entry(user, page_index(start, count, 5), filter),
+            user_campaign_entry(user, page_index(start, count, 6), filter),
+            user_campaign_entry(user, page_index(start, count, 7), filter),
+            user_campaign_entry(user, page_index(start, count, 8), filter),
+            user_campaign_entry(user, page_index(start, count, 9), filter),
+        ]
+    }
+
+    /// Returns a page of up to 10 pledges that the user has made
+    ///
+    /// Pledges to campaigns that do not match the `filter` are returned as empty entries with an
+    /// `id` of 0 so that the next page always begins at `start + count`
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user who has pledged
+    /// * `start` - Number from 1...pledge_count of the first pledge in the page
+    /// * `count` - The number of pledges in the page which is capped at 10
+    /// * `filter` - The state that the pledged campaign must be in to be included in the page
+    #[storage(read)]fn user_pledges(user: Identity, start: u64, count: u64, filter: CampaignFilter) -> [Pledge;
+    10] {
+        [
+            pledge_entry(user, page_index(start, count, 0), filter),
+            pledge_entry(user, page_index(start, count, 1), filter),
+            pledge_entry(user, page_index(start, count, 2), filter),
+            pledge_entry(user, page_index(start, count, 3), filter),
+            pledge_entry(user, page_index(start, count, 4), filter),
+            pledge_entry(user, page_index(start, count, 5), filter),
+            pledge_entry(user, page_index(start, count, 6), filter),
+            pledge_entry(user, page_index(start, count, 7), filter),
+            pledge_entry(user, page_index(start, count, 8), filter),
+            pledge_entry(user, page_index(start, count, 9), filter),
+        ]
+    }
+
+    /// Returns the number of unique users that have pledged to a campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn backer_count(id: u64) -> u64 {
+        storage.backer_count.get(id)
+    }
+
+    /// Returns the user at the specified position in the backers of a campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `index` - Number from 1...backer_count identifying the backer
+    ///
+    /// # Reverts
+    ///
+    /// * When the `index` is either 0 or greater than the number of backers of the campaign
+    #[storage(read)]fn backer(id: u64, index: u64) -> Identity {
+        validate_id(index, storage.backer_count.get(id));
+        storage.backers.get((id, index))
+    }
+
+    /// Returns information about the sponsor pool of a campaign
+    ///
+    /// A `cap` of 0 indicates that the campaign does not have a sponsor
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn sponsor_pool(id: u64) -> SponsorPool {
+        storage.sponsor_pools.get(id)
+    }
+
+    /// Returns the amount of the sponsor pool that is matching the pledge of the user
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who may have pledged
+    #[storage(read)]fn sponsor_matched(id: u64, user: Identity) -> u64 {
+        storage.sponsor_matched.get((user, id))
+    }
+
+    /// Returns the platform fee in basis points deducted from successful campaigns
+    #[storage(read)]fn fee() -> u64 {
+        storage.fee
+    }
+
+    /// Returns the user who is able to withdraw the accrued fees
+    #[storage(read)]fn fee_recipient() -> Identity {
+        storage.fee_recipient
+    }
+
+    /// Returns the amount of fees that have accrued in the asset and have not been withdrawn
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - The asset in which the fees have accrued
+    #[storage(read)]fn accrued_fees(asset: ContractId) -> u64 {
+        storage.fees.get(asset)
+    }
+
+    /// Returns whether the campaign only accepts pledges from the users on its allowlist
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn is_private(id: u64) -> bool {
+        storage.private_campaigns.get(id)
+    }
+
+    /// Returns whether the user is on the allowlist of the campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    /// * `user` - The user who may be able to pledge
+    #[storage(read)]fn allowlisted(id: u64, user: Identity) -> bool {
+        storage.allowlist.get((id, user))
+    }
+
+    /// Returns the number of campaigns that have not been cancelled, claimed or finalized
+    #[storage(read)]fn active_campaign_count() -> u64 {
+        storage.status_campaign_count.get(CampaignStatus::Active)
+    }
+
+    /// Returns the ID of an active campaign or 0 if the `index` is out of range
+    ///
+    /// The order of active campaigns changes as campaigns are cancelled, claimed or finalized
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A number from the active status_campaign_count range
+    #[storage(read)]fn active_campaign(index: u64) -> u64 {
+        storage.status_campaigns.get((CampaignStatus::Active, index))
+    }
+
+    /// Returns the number of campaigns that currently have the `status`
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the campaigns to count
+    #[storage(read)]fn status_campaign_count(status: CampaignStatus) -> u64 {
+        storage.status_campaign_count.get(status)
+    }
+
+    /// Returns the ID of a campaign with the `status` or 0 if the `index` is out of range
+    ///
+    /// The order of the campaigns changes as campaigns move between statuses
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the campaign
+    /// * `index` - A number from the status_campaign_count range of the `status`
+    #[storage(read)]fn status_campaign(status: CampaignStatus, index: u64) -> u64 {
+        storage.status_campaigns.get((status, index))
+    }
+
+    /// Returns the number of campaigns that have been created in the `category`
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The topic of the campaigns
+    #[storage(read)]fn category_campaign_count(category: u64) -> u64 {
+        storage.category_campaign_count.get(category)
+    }
+
+    /// Returns the ID of a campaign in the `category` or 0 if the `index` is out of range
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The topic of the campaigns
+    /// * `index` - A number from the category_campaign_count range of the `category`
+    #[storage(read)]fn category_campaign(category: u64, index: u64) -> u64 {
+        storage.category_campaigns.get((category, index))
+    }
+
+    /// Returns the extension that the author has requested for a campaign
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn extension_info(id: u64) -> ExtensionInfo {
+        storage.extensions.get(id)
+    }
+
+    /// Returns the target of a campaign which is denominated in a quote unit
+    ///
+    /// A `target` of 0 indicates that the campaign uses its `target_amount`
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique campaign identifier which is a number from the storage.total_campaigns range
+    #[storage(read)]fn quote_target(id: u64) -> QuoteTarget {
+        storage.quote_targets.get(id)
+    }
+
+    /// Returns the number of campaigns that an author has created, claimed, cancelled and
+    /// finalized as failed
+    ///
+    /// # Arguments
+    ///
+    /// * `author` - The user who has created the campaigns
+    #[storage(read)]fn author_stats(author: Identity) -> AuthorStats {
+        storage.author_stats.get(author)
+    }
+
+    /// Returns the total pledge of the campaigns in the `asset` that an author has claimed
+    ///
+    /// # Arguments
+    ///
+    /// * `author` - The user who has created the campaigns
+    /// * `asset` - The asset that the campaigns accepted as a deposit
+    #[storage(read)]fn author_raised(author: Identity, asset: ContractId) -> u64 {
+        storage.author_raised.get((author, asset))
+    }
+
+    /// Returns the total number of subscriptions that have been created by all users
+    #[storage(read)]fn subscription_count() -> u64 {
+        storage.subscription_count
+    }
+
+    /// Returns information about the specified subscription
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - Unique subscription identifier which is a number from the storage.subscription_count range
+    #[storage(read)]fn subscription(subscription_id: u64) -> Subscription {
+        storage.subscriptions.get(subscription_id)
+    }
+}
+
+/// Adds the `amount` to the pledge of the `user`, creating the pledge if they have not pledged to
+/// the campaign before
+#[storage(read, write)]fn add_to_pledge(id: u64, user: Identity, amount: u64) {
+    let pledge_count = storage.pledge_count.get(user);
+
+    // Fetch the index to see if the user has pledged to this campaign before or if this is a
+    // pledge to a new campaign
+    let pledge_history_index = storage.pledge_history_index.get((user, id));
+
+    // Pledging to a campaign that they have already pledged to
+    if pledge_history_index != 0 {
+        // 0 is the sentinel therefore they have pledged to this ID (campaign)
+        // increment their previous amount with the current pledge and update their pledge
+        let mut pledge = storage.pledge_history.get((user, pledge_history_index));
+        pledge.amount = pledge.amount + amount;
+
+        storage.pledge_history.insert((user, pledge_history_index), pledge);
+    }
+    // Pledging to a new campaign
+    else {
+        // First time pledge to this campaign therefore increment everything by 1
+        storage.pledge_count.insert(user, pledge_count + 1);
+
+        // Store the data structure required to look up the campaign they have pledged to, also
+        // track how much they have pledged so that they can withdraw the correct amount.
+        // Moreover, this can be used to show the user how much they have pledged to any campaign
+        storage.pledge_history.insert((user, pledge_count + 1), Pledge {
+            amount, id
+        });
+
+        // Since we use the campaign ID to interact with the contract use the ID as a key for
+        // a reverse look-up. Value is the 1st pledge (count)
+        storage.pledge_history_index.insert((user, id), pledge_count + 1);
+
+        // Add the user to the backers of the campaign so that they can be refunded in a batch
+        let backer_count = storage.backer_count.get(id);
+        storage.backer_count.insert(id, backer_count + 1);
+        storage.backers.insert((id, backer_count + 1), user);
+    }
+}
+
+/// Accrues the platform fee on the `amount` and returns what remains for the beneficiaries
+#[storage(read, write)]fn deduct_fee(amount: u64, asset: ContractId) -> u64 {
+    let fee = mul_div(amount, storage.fee, TOTAL_SHARES);
+    storage.fees.insert(asset, storage.fees.get(asset) + fee);
+    amount - fee
+}
+
+/// Lowers the weight with which the user has voted for a pending extension to their remaining
+/// pledge so that unpledged or transferred amounts are not counted
+#[storage(read, write)]fn reduce_extension_vote(id: u64, user: Identity, pledge: u64) {
+    let weight = storage.extension_votes.get((user, id));
+    let mut extension_info = storage.extensions.get(id);
+
+    if !extension_info.approved && pledge < weight {
+        extension_info.yes_votes = extension_info.yes_votes - (weight - pledge);
+        storage.extensions.insert(id, extension_info);
+        storage.extension_votes.insert((user, id), pledge);
+    }
+}
+
+/// Returns the status of a campaign at the current block height
+///
+/// A campaign stays `Active` until it is cancelled, claimed or finalized therefore an active campaign
+/// which has reached its deadline is treated as succeeded or failed depending on its target so that
+/// every function agrees on its outcome before anyone has finalized it
+#[storage(read)]fn effective_status(id: u64, campaign_info: CampaignInfo) -> CampaignStatus {
+    if campaign_info.status == CampaignStatus::Active && campaign_info.deadline <= height() {
+        if target_reached(id, campaign_info) {
+            CampaignStatus::Succeeded
+        } else {
+            CampaignStatus::Failed
+        }
+    } else {
+        campaign_info.status
+    }
+}
+
+/// Returns whether the total pledge of a campaign has reached its target
+///
+/// If the campaign has a quote target then the total pledge is valued at the price recorded by
+/// `record_price`. Until the recording period is over a campaign without a recorded price cannot
+/// be judged and one whose price was never recorded has not reached its target
+#[storage(read)]fn target_reached(id: u64, campaign_info: CampaignInfo) -> bool {
+    let quote_target = storage.quote_targets.get(id);
+
+    if quote_target.target == 0 {
+        campaign_info.target_amount <= campaign_info.total_pledge
+    } else if quote_target.price == 0 {
+        // The outcome is unknown until the price is recorded or the time to record it has run out
+        require(campaign_info.deadline + PRICE_RECORDING_PERIOD < height(), CampaignError::PriceNotRecorded);
+        false
+    } else {
+        // Scale the target instead of dividing the value so that the comparison is made in 128 bits
+        // and cannot fail for a value of the total pledge that does not fit in 64 bits
+        let target = ~U128::from(0, quote_target.target) * ~U128::from(0, PRICE_PRECISION);
+        let value = ~U128::from(0, campaign_info.total_pledge) * ~U128::from(0, quote_target.price);
+        !(value < target)
+    }
+}
+
+/// Returns the number of stretch goals reached by the total pledge and logs each stretch goal that
+/// has been crossed since the reached number was recorded
+///
+/// The number is frozen once the deadline has been reached so that the outcome of the campaign is
+/// not rewritten by refunds
+fn update_stretch_goals(id: u64, campaign_info: CampaignInfo) -> u64 {
+    let stretch_goals = campaign_info.stretch_goals;
+    let total_pledge = campaign_info.total_pledge;
+    let mut reached = campaign_info.stretch_goals_reached;
+
+    if height() < campaign_info.deadline {
+        // Stretch goals are ascending therefore the next goal can only be reached after the previous
+        let mut crossing = true;
+        while crossing && reached < 4 {
+            let target = stretch_goals[reached];
+
+            if target != 0 && target <= total_pledge {
+                reached += 1;
+
+                log(FundraiserEvent::ReachedStretchGoal(ReachedStretchGoalEvent {
+                    goal: reached, id, target
+                }));
+            } else {
+                crossing = false;
+            }
+        }
+
+        // Likewise the last goal that was reached is the first to be lost
+        crossing = true;
+        while crossing && 0 < reached {
+            let target = stretch_goals[reached - 1];
+
+            if total_pledge < target {
+                log(FundraiserEvent::UnreachedStretchGoal(UnreachedStretchGoalEvent {
+                    goal: reached, id, target
+                }));
+
+                reached -= 1;
+            } else {
+                crossing = false;
+            }
+        }
+    }
+
+    reached
+}
+
+/// Marks the sponsor pool as settled and transfers the `amount` back to the sponsor
+#[storage(read, write)]fn settle_sponsor_pool(id: u64, asset: ContractId, amount: u64) {
+    let mut pool = storage.sponsor_pools.get(id);
+    pool.settled = true;
+    storage.sponsor_pools.insert(id, pool);
+
+    if amount != 0 {
+        transfer(amount, asset, pool.sponsor);
+    }
+
+    log(FundraiserEvent::SponsorRefunded(SponsorRefundedEvent {
+        amount, id, sponsor: pool.sponsor
+    }));
+}
+
+/// Returns the campaign as an entry in a page or an empty entry if it does not exist or does not
+/// match the `filter`
+#[storage(read)]fn campaign_entry(id: u64, filter: CampaignFilter) -> CampaignEntry {
+    let info = storage.campaign_info.get(id);
+
+    if id != 0 && id <= storage.total_campaigns && matches_filter(info, filter) {
+        CampaignEntry {
+            id, info
+        }
+    } else {
+        // Campaign 0 is never created so its information is empty
+        CampaignEntry {
+            id: 0, info: storage.campaign_info.get(0)
+        }
+    }
+}
+
+/// Returns the campaign created by the user at `index` as an entry in a page
+#[storage(read)]fn user_campaign_entry(user: Identity, index: u64, filter: CampaignFilter) -> CampaignEntry {
+    if index != 0 && index <= storage.user_campaign_count.get(user) {
+        campaign_entry(storage.campaign_history.get((user, index)).id, filter)
+    } else {
+        campaign_entry(0, filter)
+    }
+}
+
+/// Returns the pledge made by the user at `index` as an entry in a page or an empty entry if it
+/// does not exist or the campaign does not match the `filter`
+#[storage(read)]fn pledge_entry(user: Identity, index: u64, filter: CampaignFilter) -> Pledge {
+    let pledge = storage.pledge_history.get((user, index));
+
+    if index != 0 && index <= storage.pledge_count.get(user) && matches_filter(storage.campaign_info.get(pledge.id), filter) {
+        pledge
+    } else {
+        Pledge {
+            amount: 0, id: 0
+        }
+    }
+}
+
+/// Records a pledge of the forwarded amount against the `user` and matches it from the sponsor pool
+#[storage(read, write)]fn receive_pledge(id: u64, user: Identity) {
+    // User cannot interact with a non-existent campaign
+    validate_id(id, storage.total_campaigns);
+
+    // Retrieve the campaign in order to check its data / update it
+    let mut campaign_info = storage.campaign_info.get(id);
+
+    // The users should only have the ability to pledge to campaigns that have not reached their
+    // deadline (ended naturally - not been cancelled)
+    require(height() < campaign_info.deadline, CampaignError::CampaignEnded);
+
+    // The campaign specifies an asset that it accepts therefore the user must pledge the correct
+    // asset in order to update the state of the campaign
+    require(campaign_info.asset == msg_asset_id(), UserError::IncorrectAssetSent);
+
+    // A user cannot pledge zero since it does not make sense to do so
+    require(0 < msg_amount(), UserError::AmountCannotBeZero);
+
+    // The user should not be able to continue to pledge if the campaign has been cancelled
+    // Given the logic below it's unnecessary but it makes sense to stop them
+    require(campaign_info.status != CampaignStatus::Cancelled, CampaignError::CampaignHasBeenCancelled);
+
+    // A private campaign only accepts pledges from the users that the author has approved
+    require(!storage.private_campaigns.get(id) || storage.allowlist.get((id, user)), UserError::NotAllowlisted);
+
+    // Record the pledge against the user so that they can unpledge it
+    add_to_pledge(id, user, msg_amount());
+
+    // The user has pledged therefore we increment the total amount that this campaign has
+    // received.
+    campaign_info.total_pledge = campaign_info.total_pledge + msg_amount();
+
+    // Match the pledge 1:1 with whatever remains in the sponsor pool
+    let mut pool = storage.sponsor_pools.get(id);
+    let mut matched = pool.cap - pool.matched;
+    if msg_amount() < matched {
+        matched = msg_amount();
+    }
+
+    if matched != 0 {
+        pool.matched = pool.matched + matched;
+        storage.sponsor_pools.insert(id, pool);
+        storage.sponsor_matched.insert((user, id), storage.sponsor_matched.get((user, id)) + matched);
+
+        campaign_info.total_pledge = campaign_info.total_pledge + matched;
+
+        log(FundraiserEvent::Matched(MatchedEvent {
+            amount: matched, id, user
+        }));
+    }
+
+    // The pledge may have crossed the next stretch goals
+    campaign_info.stretch_goals_reached = update_stretch_goals(id, campaign_info);
+
+    // Campaign state has been updated therefore overwrite the previous version with the new
+    storage.campaign_info.insert(id, campaign_info);
+
+    // Update the asset amount to track the addition of the new pledge
+    let mut asset_info = storage.asset_info.get(campaign_info.asset);
+    asset_info.amount = asset_info.amount + msg_amount();
+
+    // Update asset state
+    storage.asset_info.insert(campaign_info.asset, asset_info);
+
+    // We have updated the state of a campaign therefore we must log it
+    log(FundraiserEvent::Pledged(PledgedEvent {
+        amount: msg_amount(), id, user
+    }));
+}
+
+/// Appends the campaign to the list of campaigns with the `status`
+#[storage(read, write)]fn add_status_campaign(id: u64, status: CampaignStatus) {
+    let count = storage.status_campaign_count.get(status) + 1;
+    storage.status_campaign_count.insert(status, count);
+    storage.status_campaigns.insert((status, count), id);
+    storage.status_campaign_position.insert(id, count);
+}
+
+/// Removes the campaign from the list of campaigns with the `status` that it currently has
+///
+/// The last campaign with the `status` is moved into the position of the removed campaign so that
+/// the list remains contiguous
+#[storage(read, write)]fn remove_status_campaign(id: u64, status: CampaignStatus) {
+    let position = storage.status_campaign_position.get(id);
+    let count = storage.status_campaign_count.get(status);
+
+    // Move the last campaign into the vacated position
+    let last_campaign = storage.status_campaigns.get((status, count));
+    storage.status_campaigns.insert((status, position), last_campaign);
+    storage.status_campaign_position.insert(last_campaign, position);
+
+    storage.status_campaigns.insert((status, count), 0);
+    storage.status_campaign_count.insert(status, count - 1);
+
+    // Reset the position last since the campaign may have been the last in the list
+    storage.status_campaign_position.insert(id, 0);
+}
+
+/// Returns the NFT supply reserved by the reward tiers of a campaign which will not be minted
+///
+/// When `keep_selected` is true the receipts selected by backers remain reserved so that they can
+/// still be minted through claim_reward
+#[storage(read, write)]fn release_reward_tiers(id: u64, keep_selected: bool) {
+    let reward_tier_count = storage.reward_tier_count.get(id);
+    let mut tier = 1;
+    while tier <= reward_tier_count {
+        let reward_tier = storage.reward_tiers.get((id, tier));
+
+        let mut released = reward_tier.quantity;
+        if keep_selected {
+            released = reward_tier.quantity - reward_tier.selected;
+        }
+
+        let reserved = storage.nft_reserved.get(reward_tier.nft);
+        storage.nft_reserved.insert(reward_tier.nft, reserved - released);
+
+        tier += 1;
+    }
+}
+
+/// Removes the user from the backers of the reward tier that they have selected, if any
+///
+/// The last backer of the tier is moved into the position of the removed user so that the list of
+/// backers remains contiguous
+#[storage(read, write)]fn remove_reward_selection(id: u64, user: Identity) {
+    let selection = storage.reward_selection.get((user, id));
+
+    if selection.tier != 0 {
+        let mut reward_tier = storage.reward_tiers.get((id, selection.tier));
+
+        // Move the last backer into the vacated position
+        let last_backer = storage.reward_backers.get((id, selection.tier, reward_tier.selected));
+        storage.reward_backers.insert((id, selection.tier, selection.position), last_backer);
+        storage.reward_selection.insert((last_backer, id), selection);
+
+        reward_tier.selected = reward_tier.selected - 1;
+        storage.reward_tiers.insert((id, selection.tier), reward_tier);
+
+        // Reset the selection last since the user may have been the last backer
+        storage.reward_selection.insert((user, id), RewardSelection {
+            position: 0,
+            tier: 0,
+        });
+
+        log(FundraiserEvent::RemovedRewardSelection(RemovedRewardSelectionEvent {
+            id, tier: selection.tier, user
+        }));
     }
 }
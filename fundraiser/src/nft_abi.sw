@@ -0,0 +1,12 @@
+library nft_abi;
+
+use std::identity::Identity;
+
+/// Subset of the NFT application's interface that the fundraiser requires to mint receipts
+abi NFT {
+    #[storage(read)]fn admin() -> Identity;
+
+    #[storage(read)]fn max_supply() -> u64;
+
+    #[storage(read, write)]fn mint(amount: u64, to: Identity);
+}
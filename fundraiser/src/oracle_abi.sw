@@ -0,0 +1,9 @@
+library oracle_abi;
+
+use std::contract_id::ContractId;
+
+/// Interface of a price oracle which the fundraiser uses to value pledges in a quote unit
+abi Oracle {
+    /// Returns the value of one unit of the `asset` in the quote unit scaled by the price precision
+    #[storage(read)]fn price(asset: ContractId) -> u64;
+}
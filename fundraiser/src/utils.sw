@@ -1,9 +1,96 @@
 library utils;
 
+dep data_structures;
 dep errors;
 
-use std::revert::require;
-use errors::UserError;
+use std::{
+    contract_id::ContractId,
+    result::Result,
+    revert::require,
+    token::transfer,
+    u128::U128,
+};
+use data_structures::{Beneficiary, CampaignFilter, CampaignInfo};
+use errors::{CreationError, UserError};
+
+/// The scale of the prices reported by an oracle
+pub const PRICE_PRECISION: u64 = 1000000000;
+
+/// The sum of the shares of all beneficiaries of a campaign in basis points
+pub const TOTAL_SHARES: u64 = 10000;
+
+/// The maximum number of blocks by which the deadline of a campaign can be extended
+pub const MAX_EXTENSION: u64 = 10000;
+
+/// The maximum number of blocks that backers can be given to vote on a tranche
+pub const MAX_VOTING_PERIOD: u64 = 10000;
+
+/// The number of voting periods that the author has to propose each tranche
+pub const PROPOSAL_PERIODS: u64 = 10;
+
+/// The number of blocks after the deadline during which the price of a quote target can be recorded
+pub const PRICE_RECORDING_PERIOD: u64 = 100;
+
+/// Returns whether the campaign should be included in a page with the given filter
+pub fn matches_filter(campaign_info: CampaignInfo, filter: CampaignFilter) -> bool {
+    match filter {
+        CampaignFilter::All => true,
+        CampaignFilter::Status(status) => campaign_info.status == status,
+    }
+}
+
+/// Returns `amount * numerator / denominator` without overflowing the intermediate product
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> u64 {
+    let result = ~U128::from(0, amount) * ~U128::from(0, numerator) / ~U128::from(0, denominator);
+    result.as_u64().unwrap()
+}
+
+/// Returns the index of the entry at `offset` in a page starting from `start`
+///
+/// 0 is returned for offsets beyond the requested `count` which results in an empty entry
+pub fn page_index(start: u64, count: u64, offset: u64) -> u64 {
+    if offset < count { start + offset } else { 0 }
+}
+
+/// Reverts unless the shares of the beneficiaries sum to TOTAL_SHARES and the first beneficiary,
+/// who receives any rounding dust, has a share
+pub fn validate_beneficiaries(beneficiaries: [Beneficiary;
+    4]) {
+    require(beneficiaries[0].share != 0, CreationError::FirstShareCannotBeZero);
+
+    let mut total = 0;
+    let mut index = 0;
+    while index < 4 {
+        total = total + beneficiaries[index].share;
+        index += 1;
+    }
+
+    require(total == TOTAL_SHARES, CreationError::SharesMustSumToTotal);
+}
+
+/// Splits the `amount` between the beneficiaries according to their shares
+///
+/// Each share is rounded down and the remaining dust is sent to the first beneficiary
+pub fn transfer_to_beneficiaries(amount: u64, asset: ContractId, beneficiaries: [Beneficiary;
+    4]) {
+    let mut remainder = amount;
+    let mut index = 1;
+    while index < 4 {
+        let beneficiary = beneficiaries[index];
+        let share = mul_div(amount, beneficiary.share, TOTAL_SHARES);
+
+        if share != 0 {
+            remainder = remainder - share;
+            transfer(share, asset, beneficiary.recipient);
+        }
+
+        index += 1;
+    }
+
+    if remainder != 0 {
+        transfer(remainder, asset, beneficiaries[0].recipient);
+    }
+}
 
 pub fn validate_id(id: u64, count: u64) {
     require(id != 0 && id <= count, UserError::InvalidID);
@@ -0,0 +1,25 @@
+contract;
+
+use std::{contract_id::ContractId, storage::StorageMap};
+
+abi Oracle {
+    #[storage(read)]fn price(asset: ContractId) -> u64;
+
+    #[storage(write)]fn set_price(asset: ContractId, price: u64);
+}
+
+storage {
+    prices: StorageMap<ContractId,
+    u64> = StorageMap {
+    },
+}
+
+impl Oracle for Contract {
+    #[storage(read)]fn price(asset: ContractId) -> u64 {
+        storage.prices.get(asset)
+    }
+
+    #[storage(write)]fn set_price(asset: ContractId, price: u64) {
+        storage.prices.insert(asset, price);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::utils::{
+    abi_calls::{
+        abandon_milestones, claim_pledges, create_campaign, milestone_info, pledge,
+        propose_tranche, reclaim_remainder, set_milestones,
+    },
+    test_helpers::{advance_to, mint, setup, single_beneficiary},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn abandons_milestones_after_proposal_deadline() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // The author never proposes a tranche
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(deadline + 10, info.proposal_deadline);
+        advance_to(&user.wallet, info.proposal_deadline - 1).await;
+
+        abandon_milestones(&user.contract, 1).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(true, info.failed);
+        assert_eq!(0, info.released);
+
+        // The backers can now reclaim the entire pledge
+        reclaim_remainder(&user.contract, 1).await;
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_proposal_deadline_not_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        abandon_milestones(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_vote_is_in_progress() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+        propose_tranche(&author.contract, 1).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        advance_to(&user.wallet, info.proposal_deadline).await;
+
+        // Reverts
+        abandon_milestones(&user.contract, 1).await;
+    }
+}
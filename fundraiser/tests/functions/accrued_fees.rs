@@ -0,0 +1,48 @@
+use crate::utils::{
+    abi_calls::{accrued_fees, claim_pledges, constructor, create_campaign, pledge},
+    test_helpers::{mint, setup_uninitialized},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_accrued_fees() {
+        let (author, user, asset, _, defaults) = setup_uninitialized().await;
+        let deadline = 7;
+
+        constructor(
+            &author.contract,
+            1_000,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(0, accrued_fees(&author.contract, &asset.id).await);
+
+        claim_pledges(&author.contract, 1).await;
+
+        // 512 * 1_000 / 10_000 rounds down to 51
+        assert_eq!(51, accrued_fees(&author.contract, &asset.id).await);
+    }
+}
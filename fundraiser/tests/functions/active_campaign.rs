@@ -0,0 +1,109 @@
+use crate::utils::{
+    abi_calls::{
+        active_campaign, active_campaign_count, cancel_campaign, claim_pledges, create_campaign,
+        pledge,
+    },
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_active_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        assert_eq!(0, active_campaign_count(&author.contract).await);
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(2, active_campaign_count(&author.contract).await);
+        assert_eq!(1, active_campaign(&author.contract, 1).await);
+        assert_eq!(2, active_campaign(&author.contract, 2).await);
+    }
+
+    #[tokio::test]
+    async fn removes_cancelled_campaign() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        cancel_campaign(&author.contract, 1).await;
+
+        // The last active campaign is moved into the vacated position
+        assert_eq!(1, active_campaign_count(&author.contract).await);
+        assert_eq!(2, active_campaign(&author.contract, 1).await);
+        assert_eq!(0, active_campaign(&author.contract, 2).await);
+    }
+
+    #[tokio::test]
+    async fn removes_claimed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(1, active_campaign_count(&author.contract).await);
+
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(0, active_campaign_count(&author.contract).await);
+        assert_eq!(0, active_campaign(&author.contract, 1).await);
+    }
+}
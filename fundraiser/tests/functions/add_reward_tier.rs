@@ -0,0 +1,398 @@
+use crate::utils::{
+    abi_calls::{
+        add_reward_tier, cancel_campaign, claim_pledges, create_campaign, finalize, pledge,
+        reward_tier, reward_tier_count, select_reward_tier,
+    },
+    test_helpers::{advance_to, deploy_nft, mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_reward_tier() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, reward_tier_count(&author.contract, 1).await);
+
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+
+        let tier = reward_tier(&author.contract, 1, 1).await;
+        assert_eq!(1, reward_tier_count(&author.contract, 1).await);
+        assert_eq!(100, tier.minimum_pledge);
+        assert_eq!(nft.id, tier.nft);
+        assert_eq!(5, tier.quantity);
+        assert_eq!(0, tier.selected);
+    }
+
+    #[tokio::test]
+    async fn adds_multiple_reward_tiers() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft1 = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+        let nft2 = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            2,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        add_reward_tier(&author.contract, 1, 100, &nft1.id, 5).await;
+        add_reward_tier(&author.contract, 1, 200, &nft2.id, 2).await;
+
+        assert_eq!(2, reward_tier_count(&author.contract, 1).await);
+        assert_eq!(nft1.id, reward_tier(&author.contract, 1, 1).await.nft);
+        assert_eq!(nft2.id, reward_tier(&author.contract, 1, 2).await.nft);
+        assert_eq!(
+            200,
+            reward_tier(&author.contract, 1, 2).await.minimum_pledge
+        );
+    }
+
+    #[tokio::test]
+    async fn reuses_nft_supply_released_by_cancelled_campaign() {
+        let (author, user, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &user.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 10).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        add_reward_tier(&user.contract, 2, 100, &nft.id, 10).await;
+
+        assert_eq!(10, reward_tier(&user.contract, 2, 1).await.quantity);
+    }
+
+    #[tokio::test]
+    async fn reuses_nft_supply_released_by_failed_campaign() {
+        let (author, user, _, _, defaults) = setup().await;
+        let deadline = 9;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &user.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 10).await;
+        advance_to(&author.wallet, deadline).await;
+        finalize(&author.contract, 1).await;
+
+        add_reward_tier(&user.contract, 2, 100, &nft.id, 10).await;
+
+        assert_eq!(10, reward_tier(&user.contract, 2, 1).await.quantity);
+    }
+
+    #[tokio::test]
+    async fn reuses_unselected_nft_supply_of_claimed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 11;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 10).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+        advance_to(&author.wallet, deadline).await;
+        claim_pledges(&author.contract, 1).await;
+        create_campaign(
+            &user.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // The selected receipt remains reserved until its backer mints it
+        add_reward_tier(&user.contract, 2, 100, &nft.id, 9).await;
+
+        assert_eq!(9, reward_tier(&user.contract, 2, 1).await.quantity);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_reward_tier(&author.contract, 0, 100, &nft.id, 5).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_reward_tier(&user.contract, 1, 100, &nft.id, 5).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_quantity_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_fundraiser_is_not_nft_admin() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::Address(author.wallet.address()),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_nft_supply_is_reserved_by_another_campaign() {
+        let (author, user, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &user.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+
+        // Reverts
+        add_reward_tier(&user.contract, 2, 100, &nft.id, 6).await;
+    }
+}
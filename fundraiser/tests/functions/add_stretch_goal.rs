@@ -0,0 +1,194 @@
+use crate::utils::{
+    abi_calls::{add_stretch_goal, campaign_info, cancel_campaign, create_campaign, pledge},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_stretch_goals() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            vec![0, 0, 0, 0],
+            campaign_info(&author.contract, 1).await.value.stretch_goals
+        );
+
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 3).await;
+
+        let info = campaign_info(&author.contract, 1).await.value;
+        assert_eq!(
+            vec![defaults.target_amount * 2, defaults.target_amount * 3, 0, 0],
+            info.stretch_goals
+        );
+        assert_eq!(0, info.stretch_goals_reached);
+    }
+
+    #[tokio::test]
+    async fn reaches_stretch_goal_below_total_pledge() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount * 2,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount * 2).await;
+
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+
+        assert_eq!(
+            1,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        // Reverts
+        add_stretch_goal(&author.contract, 0, defaults.target_amount * 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_stretch_goal(&user.contract, 1, defaults.target_amount * 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_is_not_greater_than_target_amount() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_stretch_goal(&author.contract, 1, defaults.target_amount).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_is_not_greater_than_previous_stretch_goal() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 3).await;
+
+        // Reverts
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_has_four_stretch_goals() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 3).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 4).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 5).await;
+
+        // Reverts
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 6).await;
+    }
+}
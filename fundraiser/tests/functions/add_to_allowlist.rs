@@ -0,0 +1,76 @@
+use crate::utils::{
+    abi_calls::{add_to_allowlist, allowlisted, create_campaign},
+    test_helpers::setup,
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_user() {
+        let (author, user, _, _, defaults) = setup().await;
+        let backer = Identity::Address(user.wallet.address());
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            false,
+            allowlisted(&author.contract, 1, backer.clone()).await
+        );
+
+        add_to_allowlist(&author.contract, 1, backer.clone()).await;
+
+        assert_eq!(true, allowlisted(&author.contract, 1, backer).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, _, _, _) = setup().await;
+
+        // Reverts
+        add_to_allowlist(
+            &author.contract,
+            0,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        add_to_allowlist(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+    }
+}
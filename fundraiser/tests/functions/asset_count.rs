@@ -22,8 +22,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
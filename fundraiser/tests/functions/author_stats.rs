@@ -0,0 +1,109 @@
+use crate::utils::{
+    abi_calls::{
+        author_raised, author_stats, cancel_campaign, claim_pledges, create_campaign, pledge,
+    },
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_stats() {
+        let (author, _, _, _, defaults) = setup().await;
+        let stats =
+            author_stats(&author.contract, Identity::Address(author.wallet.address())).await;
+
+        assert_eq!(0, stats.cancelled);
+        assert_eq!(0, stats.created);
+        assert_eq!(0, stats.failed);
+        assert_eq!(0, stats.successful);
+        assert_eq!(
+            0,
+            author_raised(
+                &author.contract,
+                Identity::Address(author.wallet.address()),
+                &defaults.asset_id
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn counts_created_and_cancelled_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 2).await;
+
+        let stats =
+            author_stats(&author.contract, Identity::Address(author.wallet.address())).await;
+
+        assert_eq!(1, stats.cancelled);
+        assert_eq!(2, stats.created);
+        assert_eq!(0, stats.failed);
+        assert_eq!(0, stats.successful);
+    }
+
+    #[tokio::test]
+    async fn counts_claimed_campaigns_and_amount_raised() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        let stats =
+            author_stats(&author.contract, Identity::Address(author.wallet.address())).await;
+
+        assert_eq!(1, stats.created);
+        assert_eq!(1, stats.successful);
+        assert_eq!(
+            defaults.target_amount,
+            author_raised(
+                &author.contract,
+                Identity::Address(author.wallet.address()),
+                &defaults.asset_id
+            )
+            .await
+        );
+    }
+}
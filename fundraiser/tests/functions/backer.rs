@@ -0,0 +1,102 @@
+use crate::utils::{
+    abi_calls::{backer, create_campaign, pledge},
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_backers_in_pledge_order() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            Identity::Address(user.wallet.address()),
+            backer(&author.contract, 1, 1).await
+        );
+        assert_eq!(
+            Identity::Address(author.wallet.address()),
+            backer(&author.contract, 1, 2).await
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_index_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        backer(&author.contract, 1, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_index_is_greater_than_number_of_backers() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        backer(&author.contract, 1, 1).await;
+    }
+}
@@ -0,0 +1,58 @@
+use crate::utils::{
+    abi_calls::{backer_count, create_campaign, pledge, unpledge},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, backer_count(&author.contract, 1).await);
+    }
+
+    #[tokio::test]
+    async fn counts_each_backer_once() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        assert_eq!(1, backer_count(&author.contract, 1).await);
+
+        unpledge(&user.contract, 1, defaults.target_amount).await;
+
+        assert_eq!(1, backer_count(&author.contract, 1).await);
+    }
+}
@@ -12,13 +12,15 @@ mod success {
     #[tokio::test]
     async fn returns_info() {
         let (author, _, _, _, defaults) = setup().await;
-        let deadline = 6;
+        let deadline = 7;
 
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -1,7 +1,7 @@
 use crate::utils::{
     abi_calls::{campaign_info, create_campaign},
     test_helpers::setup,
-    Identity,
+    CampaignStatus, Identity,
 };
 use fuels::signers::Signer;
 
@@ -16,8 +16,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -26,9 +28,8 @@ mod success {
 
         assert_eq!(info.asset, defaults.asset_id);
         assert_eq!(info.author, Identity::Address(author.wallet.address()));
-        assert_eq!(info.beneficiary, defaults.beneficiary);
-        assert_eq!(info.cancelled, false);
-        assert_eq!(info.claimed, false);
+        assert_eq!(info.beneficiaries, defaults.beneficiaries);
+        assert_eq!(info.status, CampaignStatus::Active());
         assert_eq!(info.deadline, defaults.deadline);
         assert_eq!(info.target_amount, defaults.target_amount);
         assert_eq!(info.total_pledge, 0);
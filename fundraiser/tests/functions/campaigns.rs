@@ -0,0 +1,201 @@
+use crate::utils::{
+    abi_calls::{campaigns, cancel_campaign, create_campaign, finalize},
+    test_helpers::{advance_to, all_campaigns, setup},
+    CampaignFilter, CampaignStatus, PAGE_SIZE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_page() {
+        let (author, _, _, _, _) = setup().await;
+
+        let page = campaigns(&author.contract, 1, PAGE_SIZE, CampaignFilter::All()).await;
+
+        assert_eq!(PAGE_SIZE as usize, page.len());
+        assert!(page.iter().all(|entry| entry.id == 0));
+    }
+
+    #[tokio::test]
+    async fn returns_page_of_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+
+        let page = campaigns(&author.contract, 1, PAGE_SIZE, CampaignFilter::All()).await;
+
+        assert_eq!(1, page[0].id);
+        assert_eq!(defaults.target_amount, page[0].info.target_amount);
+        assert_eq!(2, page[1].id);
+        assert_eq!(defaults.target_amount * 2, page[1].info.target_amount);
+        assert!(page[2..].iter().all(|entry| entry.id == 0));
+    }
+
+    #[tokio::test]
+    async fn returns_only_requested_count() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        let page = campaigns(&author.contract, 2, 1, CampaignFilter::All()).await;
+
+        assert_eq!(2, page[0].id);
+        assert!(page[1..].iter().all(|entry| entry.id == 0));
+    }
+
+    #[tokio::test]
+    async fn filters_active_and_cancelled_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        let active = all_campaigns(
+            &author.contract,
+            CampaignFilter::Status(CampaignStatus::Active()),
+        )
+        .await;
+        let cancelled = all_campaigns(
+            &author.contract,
+            CampaignFilter::Status(CampaignStatus::Cancelled()),
+        )
+        .await;
+
+        assert_eq!(1, active.len());
+        assert_eq!(2, active[0].id);
+        assert_eq!(1, cancelled.len());
+        assert_eq!(1, cancelled[0].id);
+    }
+
+    #[tokio::test]
+    async fn pages_through_all_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+        let total = PAGE_SIZE + 2;
+
+        for _ in 0..total {
+            create_campaign(
+                &author.contract,
+                &defaults.asset_id,
+                &defaults.beneficiaries,
+                defaults.category,
+                defaults.deadline,
+                &defaults.metadata,
+                defaults.target_amount,
+            )
+            .await;
+        }
+
+        let entries = all_campaigns(&author.contract, CampaignFilter::All()).await;
+
+        assert_eq!(total as usize, entries.len());
+        assert!(entries
+            .iter()
+            .zip(1..=total)
+            .all(|(entry, id)| entry.id == id));
+    }
+
+    #[tokio::test]
+    async fn filters_campaigns_by_status() {
+        let (author, _, _, _, defaults) = setup().await;
+        let deadline = 6;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        advance_to(&author.wallet, deadline).await;
+        finalize(&author.contract, 1).await;
+
+        let failed = all_campaigns(
+            &author.contract,
+            CampaignFilter::Status(CampaignStatus::Failed()),
+        )
+        .await;
+        let active = all_campaigns(
+            &author.contract,
+            CampaignFilter::Status(CampaignStatus::Active()),
+        )
+        .await;
+
+        assert_eq!(1, failed.len());
+        assert_eq!(1, failed[0].id);
+        assert_eq!(1, active.len());
+        assert_eq!(2, active[0].id);
+    }
+}
@@ -1,7 +1,12 @@
 use crate::utils::{
-    abi_calls::{campaign_info, cancel_campaign, create_campaign},
-    test_helpers::setup,
+    abi_calls::{
+        add_stretch_goal, campaign_info, cancel_campaign, create_campaign, pledge, sponsor,
+        sponsor_pool,
+    },
+    test_helpers::{advance_to, mint, setup},
+    CampaignStatus,
 };
+use fuels::{signers::Signer, tx::AssetId};
 
 mod success {
 
@@ -14,22 +19,24 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
 
         assert_eq!(
-            false,
-            campaign_info(&author.contract, 1).await.value.cancelled
+            CampaignStatus::Active(),
+            campaign_info(&author.contract, 1).await.value.status
         );
 
         cancel_campaign(&author.contract, 1).await;
 
         assert_eq!(
-            true,
-            campaign_info(&author.contract, 1).await.value.cancelled
+            CampaignStatus::Cancelled(),
+            campaign_info(&author.contract, 1).await.value.status
         );
     }
 
@@ -40,8 +47,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -49,34 +58,128 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
 
         assert_eq!(
-            false,
-            campaign_info(&author.contract, 1).await.value.cancelled
+            CampaignStatus::Active(),
+            campaign_info(&author.contract, 1).await.value.status
         );
 
         assert_eq!(
-            false,
-            campaign_info(&author.contract, 2).await.value.cancelled
+            CampaignStatus::Active(),
+            campaign_info(&author.contract, 2).await.value.status
         );
 
         cancel_campaign(&author.contract, 1).await;
 
         assert_eq!(
-            true,
-            campaign_info(&author.contract, 1).await.value.cancelled
+            CampaignStatus::Cancelled(),
+            campaign_info(&author.contract, 1).await.value.status
         );
 
         cancel_campaign(&author.contract, 2).await;
 
         assert_eq!(
-            true,
-            campaign_info(&author.contract, 2).await.value.cancelled
+            CampaignStatus::Cancelled(),
+            campaign_info(&author.contract, 2).await.value.status
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_sponsor_pool() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let cap = defaults.target_amount / 4;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(&asset.contract, cap, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, cap).await;
+        pledge(&user.contract, 1, &asset, cap / 2).await;
+
+        cancel_campaign(&author.contract, 1).await;
+
+        assert_eq!(
+            cap / 2,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert!(sponsor_pool(&author.contract, 1).await.settled);
+        assert_eq!(
+            cap,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn unreaches_stretch_goals_matched_by_sponsor() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            1,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+
+        cancel_campaign(&author.contract, 1).await;
+
+        // The sponsor match no longer counts towards the stretch goal
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
         );
     }
 }
@@ -93,8 +196,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -116,13 +221,15 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn when_sender_is_not_author() {
         let (author, user, _, _, defaults) = setup().await;
-        let deadline = 4;
+        let deadline = 5;
 
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -135,17 +242,21 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn when_calling_after_deadline() {
         let (author, _, _, _, defaults) = setup().await;
-        let deadline = 3;
+        let deadline = 5;
 
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
 
+        advance_to(&author.wallet, deadline).await;
+
         // Reverts
         cancel_campaign(&author.contract, 1).await;
     }
@@ -158,8 +269,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -0,0 +1,101 @@
+use crate::utils::{
+    abi_calls::{cancel_subscription, create_campaign, pull_subscription, subscribe, subscription},
+    test_helpers::{mint, setup},
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_remaining_balance() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+        pull_subscription(&author.contract, 1).await;
+
+        cancel_subscription(&user.contract, 1).await;
+
+        let info = subscription(&author.contract, 1).await;
+        assert_eq!(0, info.balance);
+        assert_eq!(true, info.cancelled);
+        assert_eq!(
+            200,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_subscription_id_is_zero() {
+        let (_, user, _, _, _) = setup().await;
+
+        // Reverts
+        cancel_subscription(&user.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_backer() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+
+        // Reverts
+        cancel_subscription(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_already_cancelled() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+        cancel_subscription(&user.contract, 1).await;
+
+        // Reverts
+        cancel_subscription(&user.contract, 1).await;
+    }
+}
@@ -0,0 +1,57 @@
+use crate::utils::{
+    abi_calls::{category_campaign, category_campaign_count, create_campaign},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_campaigns_by_category() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        assert_eq!(0, category_campaign_count(&author.contract, 1).await);
+        assert_eq!(0, category_campaign_count(&author.contract, 2).await);
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            1,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            2,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            1,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(2, category_campaign_count(&author.contract, 1).await);
+        assert_eq!(1, category_campaign(&author.contract, 1, 1).await);
+        assert_eq!(3, category_campaign(&author.contract, 1, 2).await);
+
+        assert_eq!(1, category_campaign_count(&author.contract, 2).await);
+        assert_eq!(2, category_campaign(&author.contract, 2, 1).await);
+
+        assert_eq!(0, category_campaign(&author.contract, 2, 2).await);
+    }
+}
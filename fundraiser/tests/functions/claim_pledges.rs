@@ -1,7 +1,12 @@
 use crate::utils::{
-    abi_calls::{campaign_info, cancel_campaign, claim_pledges, create_campaign, pledge},
-    test_helpers::{mint, setup},
-    Identity,
+    abi_calls::{
+        accrued_fees, campaign_info, cancel_campaign, claim_pledges, constructor, create_campaign,
+        finalize, pledge, quote_target, record_price, set_quote_target, sponsor, sponsor_pool,
+    },
+    test_helpers::{
+        advance_to, deploy_oracle, mint, set_price, setup, setup_uninitialized, single_beneficiary,
+    },
+    Beneficiary, CampaignStatus, Identity, PRICE_PRECISION, TOTAL_SHARES,
 };
 use fuels::{signers::Signer, tx::AssetId};
 
@@ -12,8 +17,8 @@ mod success {
     #[tokio::test]
     async fn claims() {
         let (author, user, asset, _, defaults) = setup().await;
-        let beneficiary = Identity::Address(author.wallet.address());
-        let deadline = 6;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 7;
 
         mint(
             &asset.contract,
@@ -24,8 +29,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &beneficiary,
+            &beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -51,7 +58,258 @@ mod success {
                 .await
                 .unwrap()
         );
-        assert_eq!(campaign_info(&author.contract, 1).await.value.claimed, true);
+        assert_eq!(
+            campaign_info(&author.contract, 1).await.value.status,
+            CampaignStatus::Claimed()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_unmatched_sponsor_pool() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 9;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        claim_pledges(&author.contract, 1).await;
+
+        // The beneficiary receives the pledge and the match
+        assert_eq!(
+            defaults.target_amount / 2 + defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount / 2,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert!(sponsor_pool(&author.contract, 1).await.settled);
+    }
+
+    #[tokio::test]
+    async fn splits_pledge_between_beneficiaries() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let mut beneficiaries = single_beneficiary(Identity::Address(user.wallet.address()));
+        let deadline = 7;
+
+        beneficiaries[0].share = 3333;
+        beneficiaries[1] = Beneficiary {
+            recipient: Identity::Address(author.wallet.address()),
+            share: TOTAL_SHARES - 3333,
+        };
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        claim_pledges(&author.contract, 1).await;
+
+        // 512 * 6667 / 10_000 rounds down to 341 and the dust is sent to the first beneficiary
+        assert_eq!(
+            341,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount - 341,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn deducts_platform_fee() {
+        let (author, user, asset, _, defaults) = setup_uninitialized().await;
+        let deadline = 7;
+
+        constructor(
+            &author.contract,
+            250,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        claim_pledges(&author.contract, 1).await;
+
+        // 512 * 250 / 10_000 rounds down to 12 which is kept by the contract
+        assert_eq!(12, accrued_fees(&author.contract, &asset.id).await);
+        assert_eq!(
+            defaults.target_amount - 12,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn claims_when_quote_target_is_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        // One unit of the asset is worth two units of the quote
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount * 4,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount * 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        // The total pledge is below the target amount but its value reaches the quote target
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            CampaignStatus::Claimed(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
+        assert_eq!(
+            PRICE_PRECISION * 2,
+            quote_target(&author.contract, 1).await.price
+        );
+    }
+
+    #[tokio::test]
+    async fn claims_when_value_of_total_pledge_exceeds_u64() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+        let amount = PRICE_PRECISION * 2;
+
+        // The value of the total pledge is roughly twice the maximum u64
+        set_price(&oracle, &asset.id, u64::MAX).await;
+        mint(&asset.contract, amount, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            amount * 2,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, amount).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            CampaignStatus::Claimed(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
+    }
+
+    #[tokio::test]
+    async fn claims_once_deadline_is_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 20;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        advance_to(&author.wallet, deadline).await;
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            CampaignStatus::Claimed(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
     }
 }
 
@@ -67,8 +325,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -85,8 +345,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -103,8 +365,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -113,44 +377,55 @@ mod revert {
         claim_pledges(&user.contract, 1).await;
     }
 
-    // #[tokio::test]
-    // #[should_panic(expected = "Revert(42)")]
-    // async fn when_claiming_before_deadline() {
-    //     let (author, user, asset, _, defaults) = setup().await;
-    //     let deadline = 5;
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_claiming_before_deadline() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 10;
 
-    //     mint(&asset.contract, defaults.target_amount, user.wallet.address()).await;
-    //     create_campaign(
-    //         &author.contract,
-    //         &defaults.asset_id,
-    //         &defaults.beneficiary,
-    //         deadline,
-    //         defaults.target_amount,
-    //     )
-    //     .await;
-    //     pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
 
-    //     // TODO: shift block height to be before deadline
+        advance_to(&author.wallet, deadline - 1).await;
 
-    //     // Reverts
-    //     claim_pledges(&author.contract, 1).await;
-    // }
+        // Reverts
+        claim_pledges(&author.contract, 1).await;
+    }
 
     #[tokio::test]
     #[should_panic(expected = "Revert(42)")]
     async fn when_target_amount_is_not_reached() {
         let (author, _, _, _, defaults) = setup().await;
-        let deadline = 1;
+        let deadline = 5;
 
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
 
+        advance_to(&author.wallet, deadline).await;
+
         // Reverts
         claim_pledges(&author.contract, 1).await;
     }
@@ -159,7 +434,7 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn when_claiming_more_than_once() {
         let (author, user, asset, _, defaults) = setup().await;
-        let deadline = 5;
+        let deadline = 6;
 
         mint(
             &asset.contract,
@@ -170,8 +445,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -186,7 +463,7 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn when_cancelled() {
         let (author, user, asset, _, defaults) = setup().await;
-        let deadline = 6;
+        let deadline = 7;
 
         mint(
             &asset.contract,
@@ -197,8 +474,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -208,4 +487,97 @@ mod revert {
         // Reverts
         claim_pledges(&author.contract, 1).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_quote_target_is_not_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        // One unit of the asset is worth half a unit of the quote
+        set_price(&oracle, &asset.id, PRICE_PRECISION / 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        // Reverts
+        claim_pledges(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_finalized_as_failed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount - 1).await;
+        finalize(&user.contract, 1).await;
+
+        // Reverts
+        claim_pledges(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_price_is_not_recorded() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        claim_pledges(&author.contract, 1).await;
+    }
 }
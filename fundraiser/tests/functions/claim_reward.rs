@@ -0,0 +1,206 @@
+use crate::utils::{
+    abi_calls::{
+        add_reward_tier, claim_pledges, claim_reward, create_campaign, pledge, select_reward_tier,
+    },
+    test_helpers::{advance_to, deploy_nft, mint, nft_balance, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints_receipt_to_reward_tier_backer() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 11;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+        advance_to(&author.wallet, deadline).await;
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            0,
+            nft_balance(&nft, Identity::Address(user.wallet.address())).await
+        );
+
+        claim_reward(&user.contract, 1, &nft.id).await;
+
+        assert_eq!(
+            1,
+            nft_balance(&nft, Identity::Address(user.wallet.address())).await
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        claim_reward(&user.contract, 0, &nft.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_is_not_claimed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+
+        // Reverts
+        claim_reward(&user.contract, 1, &nft.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_not_selected_reward_tier() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 10;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        advance_to(&author.wallet, deadline).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        claim_reward(&user.contract, 1, &nft.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_reward_has_already_been_claimed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 11;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+        advance_to(&author.wallet, deadline).await;
+        claim_pledges(&author.contract, 1).await;
+        claim_reward(&user.contract, 1, &nft.id).await;
+
+        // Reverts
+        claim_reward(&user.contract, 1, &nft.id).await;
+    }
+}
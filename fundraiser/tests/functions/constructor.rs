@@ -0,0 +1,55 @@
+use crate::utils::{
+    abi_calls::{constructor, fee, fee_recipient},
+    test_helpers::{setup, setup_uninitialized},
+    Identity, TOTAL_SHARES,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes() {
+        let (author, user, _, _, _) = setup_uninitialized().await;
+        let recipient = Identity::Address(user.wallet.address());
+
+        constructor(&author.contract, 250, recipient.clone()).await;
+
+        assert_eq!(250, fee(&author.contract).await);
+        assert_eq!(recipient, fee_recipient(&author.contract).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_reinitialized() {
+        let (author, _, _, _, _) = setup().await;
+
+        // Reverts
+        constructor(
+            &author.contract,
+            0,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_fee_is_greater_than_total_shares() {
+        let (author, _, _, _, _) = setup_uninitialized().await;
+
+        // Reverts
+        constructor(
+            &author.contract,
+            TOTAL_SHARES + 1,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+    }
+}
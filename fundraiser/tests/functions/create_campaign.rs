@@ -3,8 +3,8 @@ use crate::utils::{
         asset_info_by_count, campaign, campaign_info, create_campaign, total_campaigns,
         user_campaign_count,
     },
-    test_helpers::setup,
-    Identity,
+    test_helpers::{setup, setup_uninitialized},
+    Beneficiary, CampaignStatus, Identity, TOTAL_SHARES,
 };
 use fuels::signers::Signer;
 
@@ -28,8 +28,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -56,10 +58,10 @@ mod success {
         );
         assert_eq!(info.asset, defaults.asset_id);
         assert_eq!(info.author, Identity::Address(author.wallet.address()));
-        assert_eq!(info.beneficiary, defaults.beneficiary);
-        assert_eq!(info.cancelled, false);
-        assert_eq!(info.claimed, false);
+        assert_eq!(info.beneficiaries, defaults.beneficiaries);
+        assert_eq!(info.status, CampaignStatus::Active());
         assert_eq!(info.deadline, defaults.deadline);
+        assert_eq!(info.metadata, defaults.metadata);
         assert_eq!(info.target_amount, defaults.target_amount);
         assert_eq!(info.total_pledge, 0);
     }
@@ -80,8 +82,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -89,8 +93,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount * 2,
         )
         .await;
@@ -139,8 +145,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -148,8 +156,10 @@ mod success {
         create_campaign(
             &author.contract,
             &asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount * 2,
         )
         .await;
@@ -192,8 +202,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -209,10 +221,76 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             target_amount,
         )
         .await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_first_share_is_zero() {
+        let (author, user, _, _, defaults) = setup().await;
+        let mut beneficiaries = defaults.beneficiaries.clone();
+
+        beneficiaries[0].share = 0;
+        beneficiaries[1] = Beneficiary {
+            recipient: Identity::Address(user.wallet.address()),
+            share: TOTAL_SHARES,
+        };
+
+        // Reverts
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_shares_do_not_sum_to_total() {
+        let (author, _, _, _, defaults) = setup().await;
+        let mut beneficiaries = defaults.beneficiaries.clone();
+
+        beneficiaries[0].share = TOTAL_SHARES - 1;
+
+        // Reverts
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_contract_is_not_initialized() {
+        let (author, _, _, _, defaults) = setup_uninitialized().await;
+
+        // Reverts
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
 }
@@ -0,0 +1,27 @@
+use crate::utils::{
+    abi_calls::{constructor, fee},
+    test_helpers::setup_uninitialized,
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_fee() {
+        let (author, _, _, _, _) = setup_uninitialized().await;
+
+        assert_eq!(0, fee(&author.contract).await);
+
+        constructor(
+            &author.contract,
+            250,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+
+        assert_eq!(250, fee(&author.contract).await);
+    }
+}
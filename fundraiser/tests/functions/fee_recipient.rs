@@ -0,0 +1,21 @@
+use crate::utils::{
+    abi_calls::{constructor, fee_recipient},
+    test_helpers::setup_uninitialized,
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_fee_recipient() {
+        let (author, user, _, _, _) = setup_uninitialized().await;
+        let recipient = Identity::Address(user.wallet.address());
+
+        constructor(&author.contract, 250, recipient.clone()).await;
+
+        assert_eq!(recipient, fee_recipient(&author.contract).await);
+    }
+}
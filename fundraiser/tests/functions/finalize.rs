@@ -0,0 +1,207 @@
+use crate::utils::{
+    abi_calls::{
+        active_campaign_count, author_stats, campaign_info, cancel_campaign, claim_pledges,
+        create_campaign, finalize, pledge,
+    },
+    test_helpers::{mint, setup},
+    CampaignStatus, Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn finalizes_failed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount - 1).await;
+
+        assert_eq!(1, active_campaign_count(&author.contract).await);
+
+        // Any user is able to finalize the campaign
+        finalize(&user.contract, 1).await;
+
+        let stats =
+            author_stats(&author.contract, Identity::Address(author.wallet.address())).await;
+
+        assert_eq!(
+            CampaignStatus::Failed(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
+        assert_eq!(0, active_campaign_count(&author.contract).await);
+        assert_eq!(1, stats.created);
+        assert_eq!(1, stats.failed);
+        assert_eq!(0, stats.successful);
+    }
+
+    #[tokio::test]
+    async fn finalizes_successful_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        finalize(&user.contract, 1).await;
+
+        assert_eq!(
+            CampaignStatus::Succeeded(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
+        assert_eq!(
+            0,
+            author_stats(&author.contract, Identity::Address(author.wallet.address()))
+                .await
+                .failed
+        );
+
+        // The author is still able to claim a campaign that has been finalized as successful
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            CampaignStatus::Claimed(),
+            campaign_info(&author.contract, 1).await.value.status
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, _) = setup().await;
+
+        // Reverts
+        finalize(&author.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_deadline_is_not_reached() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        finalize(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, _, _, _, defaults) = setup().await;
+        let deadline = 6;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        finalize(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_already_finalized() {
+        let (author, _, _, _, defaults) = setup().await;
+        let deadline = 5;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        finalize(&author.contract, 1).await;
+
+        // Reverts
+        finalize(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_claimed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        finalize(&user.contract, 1).await;
+    }
+}
@@ -0,0 +1,67 @@
+use crate::utils::{
+    abi_calls::{create_campaign, milestone_info, set_milestones},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_milestone_info() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, milestone_info(&author.contract, 1).await.tranches);
+
+        set_milestones(&author.contract, 1, 2, 5).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(2, info.tranches);
+        assert_eq!(5, info.voting_period);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        milestone_info(&author.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_greater_than_number_of_campaigns() {
+        let (author, _, _, _, _) = setup().await;
+
+        // Reverts
+        milestone_info(&author.contract, 1).await;
+    }
+}
@@ -1,14 +1,61 @@
+mod abandon_milestones;
+mod accrued_fees;
+mod active_campaign;
+mod add_reward_tier;
+mod add_stretch_goal;
+mod add_to_allowlist;
 mod asset_count;
 mod asset_info_by_count;
 mod asset_info_by_id;
+mod author_stats;
+mod backer;
+mod backer_count;
 mod campaign;
 mod campaign_info;
+mod campaigns;
 mod cancel_campaign;
+mod cancel_subscription;
+mod category_campaign;
 mod claim_pledges;
+mod claim_reward;
+mod constructor;
 mod create_campaign;
+mod fee;
+mod fee_recipient;
+mod finalize;
+mod milestone_info;
 mod pledge;
 mod pledge_count;
+mod pledge_for;
 mod pledged;
+mod propose_tranche;
+mod pull_subscription;
+mod reclaim_remainder;
+mod record_price;
+mod refund_batch;
+mod refund_sponsor;
+mod release_tranche;
+mod remove_from_allowlist;
+mod request_extension;
+mod reward_selection;
+mod reward_tier;
+mod reward_tier_count;
+mod select_reward_tier;
+mod set_milestones;
+mod set_private;
+mod set_quote_target;
+mod sponsor;
+mod sponsor_matched;
+mod sponsor_pool;
+mod status_campaign;
+mod subscribe;
 mod total_campaigns;
+mod transfer_pledge;
 mod unpledge;
+mod update_campaign;
 mod user_campaign_count;
+mod user_campaigns;
+mod user_pledges;
+mod vote_extension;
+mod vote_tranche;
+mod withdraw_fees;
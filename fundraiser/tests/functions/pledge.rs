@@ -1,9 +1,10 @@
 use crate::utils::{
     abi_calls::{
-        asset_info_by_count, campaign_info, cancel_campaign, create_campaign, pledge, pledge_count,
-        pledged,
+        add_stretch_goal, add_to_allowlist, asset_info_by_count, campaign_info, cancel_campaign,
+        create_campaign, pledge, pledge_count, pledged, set_private, sponsor, sponsor_matched,
+        sponsor_pool,
     },
-    test_helpers::{mint, setup},
+    test_helpers::{advance_to, mint, setup},
     Identity,
 };
 use fuels::{signers::Signer, tx::AssetId};
@@ -29,8 +30,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -99,8 +102,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -212,8 +217,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -221,8 +228,10 @@ mod success {
         create_campaign(
             &author.contract,
             &asset2_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -323,6 +332,169 @@ mod success {
                 .amount
         );
     }
+
+    #[tokio::test]
+    async fn matches_pledges_until_sponsor_pool_is_exhausted() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let cap = defaults.target_amount / 4;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(&asset.contract, cap, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, cap).await;
+
+        pledge(&user.contract, 1, &asset, cap / 2).await;
+
+        assert_eq!(
+            cap,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(cap / 2, sponsor_pool(&author.contract, 1).await.matched);
+
+        // Only the remainder of the pool is matched
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        assert_eq!(
+            cap / 2 + defaults.target_amount / 2 + cap,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(cap, sponsor_pool(&author.contract, 1).await.matched);
+        assert_eq!(
+            cap,
+            sponsor_matched(
+                &author.contract,
+                1,
+                Identity::Address(user.wallet.address())
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn pledges_to_private_campaign_when_allowlisted() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_private(&author.contract, 1, true).await;
+        add_to_allowlist(
+            &author.contract,
+            1,
+            Identity::Address(user.wallet.address()),
+        )
+        .await;
+
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
+
+    #[tokio::test]
+    async fn reaches_stretch_goals() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount * 3,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 3).await;
+
+        pledge(&user.contract, 1, &asset, defaults.target_amount * 2).await;
+
+        assert_eq!(
+            1,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            2,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+    }
+
+    #[tokio::test]
+    async fn pledges_on_last_block_before_deadline() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 10;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // The pledge is executed on the block before the deadline
+        advance_to(&user.wallet, deadline - 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
 }
 
 mod revert {
@@ -343,8 +515,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -367,8 +541,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -377,28 +553,35 @@ mod revert {
         pledge(&user.contract, 2, &asset, defaults.target_amount).await;
     }
 
-    // #[tokio::test]
-    // #[should_panic(expected = "Revert(42)")]
-    // async fn when_pledging_after_deadline() {
-    //     let (author, user, asset, _, defaults) = setup().await;
-    //     let deadline = 5;
-
-    //     mint(&asset.contract, defaults.target_amount, user.wallet.address()).await;
-    //     create_campaign(
-    //         &author.contract,
-    //         &defaults.asset_id,
-    //         &defaults.beneficiary,
-    //         deadline,
-    //         defaults.target_amount,
-    //     )
-    //     .await;
-    //     pledge(&user.contract, 1, &asset, defaults.target_amount).await;
-
-    //     // TODO: shift block height to be after deadline
-
-    //     // Reverts
-    //     pledge(&user.contract, 1, &asset, 0).await;
-    // }
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_pledging_after_deadline() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 6;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // The pledge is executed on the block of the deadline
+        advance_to(&user.wallet, deadline).await;
+
+        // Reverts
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+    }
 
     #[tokio::test]
     #[should_panic(expected = "Revert(42)")]
@@ -415,8 +598,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -440,8 +625,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -454,7 +641,7 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn when_pledging_to_cancelled_campaign() {
         let (author, user, asset, _, defaults) = setup().await;
-        let deadline = 5;
+        let deadline = 6;
 
         mint(
             &asset.contract,
@@ -465,8 +652,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -476,4 +665,31 @@ mod revert {
         // Reverts
         pledge(&user.contract, 1, &asset, defaults.target_amount).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_pledging_to_private_campaign_without_being_allowlisted() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_private(&author.contract, 1, true).await;
+
+        // Reverts
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+    }
 }
@@ -0,0 +1,198 @@
+use crate::utils::{
+    abi_calls::{
+        campaign_info, cancel_campaign, create_campaign, pledge_count, pledge_for, pledged,
+        unpledge,
+    },
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pledges_on_behalf_of_user() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let recipient = Identity::Address(author.wallet.address());
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        pledge_for(
+            &user.contract,
+            1,
+            recipient.clone(),
+            &asset,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(
+            0,
+            pledge_count(&user.contract, Identity::Address(user.wallet.address())).await
+        );
+        assert_eq!(1, pledge_count(&author.contract, recipient.clone()).await);
+
+        let info = pledged(&author.contract, 1, recipient).await.value;
+
+        assert_eq!(1, info.id);
+        assert_eq!(defaults.target_amount, info.amount);
+    }
+
+    #[tokio::test]
+    async fn recipient_can_unpledge() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge_for(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            &asset,
+            defaults.target_amount,
+        )
+        .await;
+
+        unpledge(&author.contract, 1, defaults.target_amount).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+
+        // Reverts
+        pledge_for(
+            &user.contract,
+            0,
+            Identity::Address(author.wallet.address()),
+            &asset,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_pledging_to_cancelled_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        pledge_for(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            &asset,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_original_sender_unpledges() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge_for(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            &asset,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        unpledge(&user.contract, 1, defaults.target_amount).await;
+    }
+}
@@ -0,0 +1,168 @@
+use crate::utils::{
+    abi_calls::{
+        claim_pledges, create_campaign, milestone_info, pledge, propose_tranche, set_milestones,
+    },
+    test_helpers::{mint, setup, single_beneficiary},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proposes_tranche() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 10).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(false, milestone_info(&author.contract, 1).await.voting);
+
+        propose_tranche(&author.contract, 1).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(true, info.voting);
+        assert_eq!(0, info.yes_votes);
+        assert_eq!(0, info.no_votes);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 10).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        propose_tranche(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_milestones_are_not_enabled() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        propose_tranche(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_is_not_claimed() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 10).await;
+
+        // Reverts
+        propose_tranche(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_vote_is_in_progress() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 10).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+        propose_tranche(&author.contract, 1).await;
+
+        // Reverts
+        propose_tranche(&author.contract, 1).await;
+    }
+}
@@ -0,0 +1,221 @@
+use crate::utils::{
+    abi_calls::{
+        cancel_campaign, cancel_subscription, create_campaign, pull_subscription, subscribe,
+        subscription,
+    },
+    test_helpers::{mint, setup, single_beneficiary},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pulls_once_per_period() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+
+        pull_subscription(&author.contract, 1).await;
+
+        assert_eq!(200, subscription(&author.contract, 1).await.balance);
+        assert_eq!(
+            100,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+
+        // Advance the block height until the period has elapsed
+        mint(&asset.contract, 1, user.wallet.address()).await;
+        mint(&asset.contract, 1, user.wallet.address()).await;
+
+        pull_subscription(&author.contract, 1).await;
+
+        assert_eq!(100, subscription(&author.contract, 1).await.balance);
+        assert_eq!(
+            200,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn pulls_remaining_balance() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+
+        mint(&asset.contract, 150, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 150, 100, 1).await;
+
+        pull_subscription(&author.contract, 1).await;
+        pull_subscription(&author.contract, 1).await;
+
+        assert_eq!(0, subscription(&author.contract, 1).await.balance);
+        assert_eq!(
+            150,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_subscription_id_is_zero() {
+        let (author, _, _, _, _) = setup().await;
+
+        // Reverts
+        pull_subscription(&author.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+
+        // Reverts
+        pull_subscription(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_period_has_not_elapsed() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+        pull_subscription(&author.contract, 1).await;
+
+        // Reverts
+        pull_subscription(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_balance_is_exhausted() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 100, 100, 1).await;
+        pull_subscription(&author.contract, 1).await;
+
+        // Reverts
+        pull_subscription(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+        cancel_subscription(&user.contract, 1).await;
+
+        // Reverts
+        pull_subscription(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_is_cancelled() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        pull_subscription(&author.contract, 1).await;
+    }
+}
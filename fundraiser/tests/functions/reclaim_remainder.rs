@@ -0,0 +1,214 @@
+use crate::utils::{
+    abi_calls::{
+        claim_pledges, create_campaign, pledge, propose_tranche, reclaim_remainder,
+        release_tranche, set_milestones, sponsor, vote_tranche,
+    },
+    test_helpers::{mint, setup, single_beneficiary},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reclaims_unreleased_remainder() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // First tranche is approved
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, true).await;
+        release_tranche(&user.contract, 1).await;
+
+        // Second tranche is rejected
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, false).await;
+        release_tranche(&user.contract, 1).await;
+
+        assert_eq!(
+            0,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+
+        reclaim_remainder(&user.contract, 1).await;
+
+        assert_eq!(
+            defaults.target_amount / 2,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount / 2,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn sponsor_reclaims_matched_share() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 10;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount / 2,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount / 2,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        // Half of the total pledge is matched by the sponsor
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // First tranche is approved
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, true).await;
+        release_tranche(&user.contract, 1).await;
+
+        // Second tranche is rejected
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, false).await;
+        release_tranche(&user.contract, 1).await;
+
+        reclaim_remainder(&user.contract, 1).await;
+        reclaim_remainder(&author.contract, 1).await;
+
+        // The backer and the sponsor each reclaim half of the unreleased remainder
+        assert_eq!(
+            defaults.target_amount / 4,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount / 2 + defaults.target_amount / 4,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_milestones_have_not_failed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        reclaim_remainder(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_reclaiming_twice() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, false).await;
+        release_tranche(&user.contract, 1).await;
+        reclaim_remainder(&user.contract, 1).await;
+
+        // Reverts
+        reclaim_remainder(&user.contract, 1).await;
+    }
+}
@@ -0,0 +1,222 @@
+use crate::utils::{
+    abi_calls::{create_campaign, pledge, quote_target, record_price, set_quote_target},
+    test_helpers::{advance_to, deploy_oracle, mint, set_price, setup},
+    PRICE_PRECISION, PRICE_RECORDING_PERIOD,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn records_price() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        assert_eq!(
+            PRICE_PRECISION * 2,
+            quote_target(&author.contract, 1).await.price
+        );
+    }
+
+    #[tokio::test]
+    async fn records_price_on_last_block_of_recording_period() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // The price is recorded on the last block of the recording period
+        advance_to(&user.wallet, deadline + PRICE_RECORDING_PERIOD).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        assert_eq!(
+            PRICE_PRECISION * 2,
+            quote_target(&author.contract, 1).await.price
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, _, _, _) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        // Reverts
+        record_price(&user.contract, 0, &oracle.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_has_no_quote_target() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        record_price(&user.contract, 1, &oracle.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_deadline_is_not_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 20;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        record_price(&user.contract, 1, &oracle.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_price_has_already_been_recorded() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        // Reverts
+        record_price(&user.contract, 1, &oracle.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_recording_period_has_elapsed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        advance_to(&user.wallet, deadline + PRICE_RECORDING_PERIOD + 1).await;
+
+        // Reverts
+        record_price(&user.contract, 1, &oracle.id).await;
+    }
+}
@@ -0,0 +1,552 @@
+use crate::utils::{
+    abi_calls::{
+        campaign_info, cancel_campaign, create_campaign, pledge, record_price, refund_batch,
+        set_quote_target, unpledge,
+    },
+    test_helpers::{advance_to, deploy_oracle, mint, set_price, setup},
+    PRICE_PRECISION, PRICE_RECORDING_PERIOD,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn refunds_backers_of_cancelled_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        assert_eq!(
+            defaults.target_amount * 2,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+
+        refund_batch(&user.contract, 1, 1, 2).await;
+
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_backers_of_failed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 9;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount * 3,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+
+        refund_batch(&user.contract, 1, 1, 2).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_remainder_after_partial_unpledge() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        unpledge(&user.contract, 1, defaults.target_amount / 4).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        assert_eq!(
+            defaults.target_amount / 4,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+
+        refund_batch(&author.contract, 1, 1, 1).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_backers_who_have_unpledged() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+        unpledge(&user.contract, 1, defaults.target_amount).await;
+
+        refund_batch(&author.contract, 1, 1, 2).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_in_multiple_batches() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        refund_batch(&user.contract, 1, 1, 1).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+
+        // Count beyond the last backer is capped
+        refund_batch(&user.contract, 1, 2, 10).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_backers_when_quote_target_is_not_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        set_price(&oracle, &asset.id, PRICE_PRECISION / 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+
+        // The target amount has been reached but the value of the total pledge is too low
+        refund_batch(&user.contract, 1, 1, 1).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_backers_when_count_is_max() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        refund_batch(&user.contract, 1, 1, u64::MAX).await;
+
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_backers_when_price_is_not_recorded_in_time() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 10;
+
+        // The value of the total pledge would reach the quote target had the price been recorded
+        set_price(&oracle, &asset.id, PRICE_PRECISION * 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        advance_to(&user.wallet, deadline + PRICE_RECORDING_PERIOD + 1).await;
+        refund_batch(&user.contract, 1, 1, 1).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        refund_batch(&user.contract, 0, 1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_greater_than_number_of_campaigns() {
+        let (_, user, _, _, _) = setup().await;
+
+        // Reverts
+        refund_batch(&user.contract, 1, 1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_start_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        refund_batch(&user.contract, 1, 0, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_start_is_greater_than_number_of_backers() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        refund_batch(&user.contract, 1, 2, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_deadline_has_not_been_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        refund_batch(&user.contract, 1, 1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_amount_has_been_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        refund_batch(&user.contract, 1, 1, 1).await;
+    }
+}
@@ -0,0 +1,195 @@
+use crate::utils::{
+    abi_calls::{
+        campaign_info, cancel_campaign, create_campaign, pledge, refund_sponsor, sponsor,
+        sponsor_pool,
+    },
+    test_helpers::{mint, setup},
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_sponsor_pool_of_failed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 9;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount * 4,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        refund_sponsor(&user.contract, 1).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert!(sponsor_pool(&author.contract, 1).await.settled);
+        assert_eq!(
+            defaults.target_amount,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        refund_sponsor(&author.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_does_not_have_a_sponsor() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        refund_sponsor(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sponsor_pool_has_been_settled() {
+        let (author, _, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        refund_sponsor(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_deadline_has_not_been_reached() {
+        let (author, _, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 4,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        refund_sponsor(&author.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_amount_has_been_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 9;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        // Reverts
+        refund_sponsor(&author.contract, 1).await;
+    }
+}
@@ -0,0 +1,182 @@
+use crate::utils::{
+    abi_calls::{
+        claim_pledges, create_campaign, milestone_info, pledge, propose_tranche, release_tranche,
+        set_milestones, vote_tranche,
+    },
+    test_helpers::{mint, setup, single_beneficiary},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn releases_approved_tranche() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Claiming a campaign with milestones does not transfer anything
+        assert_eq!(
+            0,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, true).await;
+        release_tranche(&user.contract, 1).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(false, info.failed);
+        assert_eq!(false, info.voting);
+        assert_eq!(1, info.released);
+        assert_eq!(defaults.target_amount / 2, info.released_amount);
+        assert_eq!(
+            defaults.target_amount / 2,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_tranche() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, false).await;
+        release_tranche(&user.contract, 1).await;
+
+        let info = milestone_info(&author.contract, 1).await;
+        assert_eq!(true, info.failed);
+        assert_eq!(false, info.voting);
+        assert_eq!(0, info.released);
+        assert_eq!(0, info.released_amount);
+        assert_eq!(
+            0,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_vote_is_not_in_progress() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        release_tranche(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_voting_period_has_not_ended() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+        let deadline = 8;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        set_milestones(&author.contract, 1, 2, 10).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+        propose_tranche(&author.contract, 1).await;
+        vote_tranche(&user.contract, 1, true).await;
+
+        // Reverts
+        release_tranche(&user.contract, 1).await;
+    }
+}
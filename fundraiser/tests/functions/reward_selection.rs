@@ -0,0 +1,54 @@
+use crate::utils::{
+    abi_calls::{add_reward_tier, create_campaign, pledge, reward_selection, select_reward_tier},
+    test_helpers::{deploy_nft, mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_reward_selection() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft.id, 5).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(0, selection.position);
+        assert_eq!(0, selection.tier);
+
+        select_reward_tier(&user.contract, 1, 1).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(1, selection.position);
+        assert_eq!(1, selection.tier);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::utils::{
+    abi_calls::{add_reward_tier, create_campaign, reward_tier},
+    test_helpers::{deploy_nft, setup},
+    Identity,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_reward_tier() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+
+        let tier = reward_tier(&author.contract, 1, 1).await;
+        assert_eq!(100, tier.minimum_pledge);
+        assert_eq!(nft.id, tier.nft);
+        assert_eq!(5, tier.quantity);
+        assert_eq!(0, tier.selected);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_tier_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        reward_tier(&author.contract, 1, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_tier_is_greater_than_number_of_tiers() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        reward_tier(&author.contract, 1, 1).await;
+    }
+}
@@ -0,0 +1,37 @@
+use crate::utils::{
+    abi_calls::{add_reward_tier, create_campaign, reward_tier_count},
+    test_helpers::{deploy_nft, setup},
+    Identity,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_reward_tier_count() {
+        let (author, _, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, reward_tier_count(&author.contract, 1).await);
+        add_reward_tier(&author.contract, 1, 100, &nft.id, 5).await;
+        assert_eq!(1, reward_tier_count(&author.contract, 1).await);
+    }
+}
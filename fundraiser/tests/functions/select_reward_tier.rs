@@ -0,0 +1,322 @@
+use crate::utils::{
+    abi_calls::{
+        add_reward_tier, create_campaign, pledge, reward_selection, reward_tier, select_reward_tier,
+    },
+    test_helpers::{deploy_nft, mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn selects_reward_tier() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(0, selection.tier);
+        assert_eq!(0, reward_tier(&user.contract, 1, 1).await.selected);
+
+        select_reward_tier(&user.contract, 1, 1).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(1, selection.tier);
+        assert_eq!(1, selection.position);
+        assert_eq!(1, reward_tier(&user.contract, 1, 1).await.selected);
+    }
+
+    #[tokio::test]
+    async fn selecting_new_tier_releases_previous_tier() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft1 = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+        let nft2 = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            2,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft1.id, 1).await;
+        add_reward_tier(&author.contract, 1, 2, &nft2.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        select_reward_tier(&user.contract, 1, 1).await;
+        select_reward_tier(&user.contract, 1, 2).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(2, selection.tier);
+        assert_eq!(0, reward_tier(&user.contract, 1, 1).await.selected);
+        assert_eq!(1, reward_tier(&user.contract, 1, 2).await.selected);
+    }
+
+    #[tokio::test]
+    async fn reselects_same_tier_when_sold_out() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        select_reward_tier(&user.contract, 1, 1).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+
+        assert_eq!(1, reward_tier(&user.contract, 1, 1).await.selected);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_tier_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        select_reward_tier(&user.contract, 1, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_tier_is_greater_than_number_of_tiers() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        select_reward_tier(&user.contract, 1, 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_not_pledged() {
+        let (author, user, _, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 0, &nft.id, 1).await;
+
+        // Reverts
+        select_reward_tier(&user.contract, 1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_pledge_is_below_minimum() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount - 1).await;
+
+        // Reverts
+        select_reward_tier(&user.contract, 1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_tier_is_sold_out() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, 1, &nft.id, 1).await;
+        pledge(&author.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&author.contract, 1, 1).await;
+
+        // Reverts
+        select_reward_tier(&user.contract, 1, 1).await;
+    }
+}
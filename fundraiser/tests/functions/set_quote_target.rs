@@ -0,0 +1,173 @@
+use crate::utils::{
+    abi_calls::{
+        cancel_campaign, create_campaign, pledge, quote_target, set_quote_target, sponsor,
+    },
+    test_helpers::{deploy_oracle, mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_quote_target() {
+        let (author, _, _, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, quote_target(&author.contract, 1).await.target);
+
+        set_quote_target(&author.contract, 1, &oracle.id, 1_000).await;
+
+        let info = quote_target(&author.contract, 1).await;
+        assert_eq!(oracle.id, info.oracle);
+        assert_eq!(0, info.price);
+        assert_eq!(1_000, info.target);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, _) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        // Reverts
+        set_quote_target(&author.contract, 0, &oracle.id, 1_000).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, _, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        set_quote_target(&user.contract, 1, &oracle.id, 1_000).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, _, _, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        set_quote_target(&author.contract, 1, &oracle.id, 1_000).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_pledged() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        set_quote_target(&author.contract, 1, &oracle.id, 1_000).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_is_sponsored() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        set_quote_target(&author.contract, 1, &oracle.id, 1_000).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        set_quote_target(&author.contract, 1, &oracle.id, 0).await;
+    }
+}
@@ -0,0 +1,53 @@
+use crate::utils::{
+    abi_calls::{create_campaign, pledge, sponsor, sponsor_matched},
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_matched_amount() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let backer = Identity::Address(user.wallet.address());
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            author.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            0,
+            sponsor_matched(&author.contract, 1, backer.clone()).await
+        );
+
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        assert_eq!(
+            defaults.target_amount / 2,
+            sponsor_matched(&author.contract, 1, backer).await
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::utils::{
+    abi_calls::{create_campaign, sponsor, sponsor_pool},
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_sponsor_pool() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, sponsor_pool(&author.contract, 1).await.cap);
+
+        sponsor(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(
+            defaults.target_amount,
+            sponsor_pool(&author.contract, 1).await.cap
+        );
+    }
+}
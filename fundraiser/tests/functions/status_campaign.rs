@@ -0,0 +1,177 @@
+use crate::utils::{
+    abi_calls::{
+        cancel_campaign, claim_pledges, create_campaign, finalize, pledge, status_campaign,
+        status_campaign_count,
+    },
+    test_helpers::{mint, setup},
+    CampaignStatus,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_created_campaigns_as_active() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        assert_eq!(
+            0,
+            status_campaign_count(&author.contract, CampaignStatus::Active()).await
+        );
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Active()).await
+        );
+        assert_eq!(
+            1,
+            status_campaign(&author.contract, CampaignStatus::Active(), 1).await
+        );
+    }
+
+    #[tokio::test]
+    async fn moves_cancelled_campaign() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        cancel_campaign(&author.contract, 1).await;
+
+        // The last active campaign is moved into the vacated position
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Active()).await
+        );
+        assert_eq!(
+            2,
+            status_campaign(&author.contract, CampaignStatus::Active(), 1).await
+        );
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Cancelled()).await
+        );
+        assert_eq!(
+            1,
+            status_campaign(&author.contract, CampaignStatus::Cancelled(), 1).await
+        );
+    }
+
+    #[tokio::test]
+    async fn moves_failed_campaign() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount - 1).await;
+        finalize(&user.contract, 1).await;
+
+        assert_eq!(
+            0,
+            status_campaign_count(&author.contract, CampaignStatus::Active()).await
+        );
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Failed()).await
+        );
+        assert_eq!(
+            1,
+            status_campaign(&author.contract, CampaignStatus::Failed(), 1).await
+        );
+    }
+
+    #[tokio::test]
+    async fn moves_claimed_campaign_from_succeeded() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        finalize(&user.contract, 1).await;
+
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Succeeded()).await
+        );
+
+        claim_pledges(&author.contract, 1).await;
+
+        assert_eq!(
+            0,
+            status_campaign_count(&author.contract, CampaignStatus::Succeeded()).await
+        );
+        assert_eq!(
+            0,
+            status_campaign(&author.contract, CampaignStatus::Succeeded(), 1).await
+        );
+        assert_eq!(
+            1,
+            status_campaign_count(&author.contract, CampaignStatus::Claimed()).await
+        );
+        assert_eq!(
+            1,
+            status_campaign(&author.contract, CampaignStatus::Claimed(), 1).await
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::utils::{
+    abi_calls::{cancel_campaign, create_campaign, subscribe, subscription, subscription_count},
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn subscribes() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(0, subscription_count(&author.contract).await);
+
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+
+        assert_eq!(1, subscription_count(&author.contract).await);
+
+        let info = subscription(&author.contract, 1).await;
+        assert_eq!(100, info.amount);
+        assert_eq!(Identity::Address(user.wallet.address()), info.backer);
+        assert_eq!(300, info.balance);
+        assert_eq!(false, info.cancelled);
+        assert_eq!(1, info.id);
+        assert_eq!(3, info.period);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (_, user, asset, _, _) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+
+        // Reverts
+        subscribe(&user.contract, 0, &asset, 300, 100, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        subscribe(&user.contract, 1, &asset, 300, 100, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sending_incorrect_asset() {
+        let (author, user, asset, asset2, defaults) = setup().await;
+
+        mint(&asset2.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        subscribe(&user.contract, 1, &asset2, 300, 100, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_amount_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        subscribe(&user.contract, 1, &asset, 300, 0, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_period_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 300, user.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        subscribe(&user.contract, 1, &asset, 300, 100, 0).await;
+    }
+}
@@ -0,0 +1,304 @@
+use crate::utils::{
+    abi_calls::{
+        backer, backer_count, campaign_info, claim_pledges, create_campaign, pledge, pledge_count,
+        pledged, sponsor, sponsor_matched, transfer_pledge,
+    },
+    test_helpers::{mint, setup},
+    Identity,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transfers_to_new_backer() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let from = Identity::Address(user.wallet.address());
+        let to = Identity::Address(author.wallet.address());
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        assert_eq!(0, pledge_count(&author.contract, to.clone()).await);
+
+        transfer_pledge(&user.contract, 1, to.clone(), 100).await;
+
+        assert_eq!(
+            defaults.target_amount - 100,
+            pledged(&user.contract, 1, from.clone()).await.value.amount
+        );
+        assert_eq!(1, pledge_count(&author.contract, to.clone()).await);
+
+        let info = pledged(&author.contract, 1, to.clone()).await.value;
+        assert_eq!(1, info.id);
+        assert_eq!(100, info.amount);
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(2, backer_count(&author.contract, 1).await);
+        assert_eq!(to, backer(&author.contract, 1, 2).await);
+    }
+
+    #[tokio::test]
+    async fn transfers_to_existing_backer() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let to = Identity::Address(author.wallet.address());
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&author.contract, 1, &asset, 100).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        transfer_pledge(&user.contract, 1, to.clone(), 100).await;
+
+        // The existing pledge is incremented rather than a new one being recorded
+        assert_eq!(1, pledge_count(&author.contract, to.clone()).await);
+        assert_eq!(200, pledged(&author.contract, 1, to).await.value.amount);
+        assert_eq!(2, backer_count(&author.contract, 1).await);
+    }
+
+    #[tokio::test]
+    async fn moves_uncovered_sponsor_match() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let from = Identity::Address(user.wallet.address());
+        let to = Identity::Address(author.wallet.address());
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, 100).await;
+        pledge(&user.contract, 1, &asset, 100).await;
+
+        transfer_pledge(&user.contract, 1, to.clone(), 40).await;
+
+        assert_eq!(60, sponsor_matched(&author.contract, 1, from).await);
+        assert_eq!(40, sponsor_matched(&author.contract, 1, to).await);
+        assert_eq!(
+            200,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, user, _, _, _) = setup().await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            0,
+            Identity::Address(author.wallet.address()),
+            100,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_amount_is_zero() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            0,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_transferring_to_self() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            1,
+            Identity::Address(user.wallet.address()),
+            100,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_not_pledged() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            100,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_amount_is_greater_than_pledge() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            defaults.target_amount + 1,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_claimed() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        transfer_pledge(
+            &user.contract,
+            1,
+            Identity::Address(author.wallet.address()),
+            100,
+        )
+        .await;
+    }
+}
@@ -1,10 +1,12 @@
 use crate::utils::{
     abi_calls::{
-        asset_info_by_count, campaign_info, claim_pledges, create_campaign, pledge, pledge_count,
-        pledged, unpledge,
+        add_reward_tier, add_stretch_goal, asset_info_by_count, campaign_info, claim_pledges,
+        create_campaign, finalize, pledge, pledge_count, pledged, record_price, reward_selection,
+        reward_tier, select_reward_tier, set_quote_target, sponsor, sponsor_matched, sponsor_pool,
+        unpledge,
     },
-    test_helpers::{mint, setup},
-    Identity,
+    test_helpers::{advance_to, deploy_nft, deploy_oracle, mint, set_price, setup},
+    Identity, PRICE_PRECISION,
 };
 use fuels::{signers::Signer, tx::AssetId};
 
@@ -29,8 +31,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -94,8 +98,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -190,16 +196,20 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
         create_campaign(
             &author.contract,
             &asset2.id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -334,8 +344,10 @@ mod success {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -397,6 +409,221 @@ mod success {
                 .unwrap()
         );
     }
+
+    #[tokio::test]
+    async fn unpledging_below_tier_minimum_removes_reward_selection() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let nft = deploy_nft(
+            &author.wallet,
+            Identity::ContractId(author.contract_id),
+            10,
+            1,
+        )
+        .await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_reward_tier(&author.contract, 1, defaults.target_amount, &nft.id, 1).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        select_reward_tier(&user.contract, 1, 1).await;
+
+        assert_eq!(1, reward_tier(&user.contract, 1, 1).await.selected);
+
+        unpledge(&user.contract, 1, 1).await;
+
+        let selection =
+            reward_selection(&user.contract, 1, Identity::Address(user.wallet.address())).await;
+        assert_eq!(0, selection.tier);
+        assert_eq!(0, reward_tier(&user.contract, 1, 1).await.selected);
+    }
+
+    #[tokio::test]
+    async fn returns_match_above_remaining_pledge_to_sponsor_pool() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let cap = defaults.target_amount / 4;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        mint(&asset.contract, cap, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&author.contract, 1, &asset, cap).await;
+        pledge(&user.contract, 1, &asset, cap).await;
+
+        assert_eq!(
+            cap * 2,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+
+        unpledge(&user.contract, 1, cap / 2).await;
+
+        assert_eq!(
+            cap,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+        assert_eq!(cap / 2, sponsor_pool(&author.contract, 1).await.matched);
+        assert_eq!(
+            cap / 2,
+            sponsor_matched(
+                &author.contract,
+                1,
+                Identity::Address(user.wallet.address())
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn unreaches_stretch_goals() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount * 3,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 3).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount * 3).await;
+
+        assert_eq!(
+            2,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+
+        unpledge(&user.contract, 1, defaults.target_amount * 2).await;
+
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_stretch_goals_after_deadline() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let oracle = deploy_oracle(&author.wallet).await;
+        let deadline = 12;
+
+        // The stretch goal is reached but the value of the total pledge is too low for the quote
+        // target which is the only way for a campaign to fail with a stretch goal reached
+        set_price(&oracle, &asset.id, PRICE_PRECISION / 2).await;
+        mint(
+            &asset.contract,
+            defaults.target_amount * 2,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+        set_quote_target(&author.contract, 1, &oracle.id, defaults.target_amount * 2).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount * 2).await;
+
+        advance_to(&user.wallet, deadline).await;
+        record_price(&user.contract, 1, &oracle.id).await;
+        unpledge(&user.contract, 1, defaults.target_amount * 2).await;
+
+        // The stretch goals reached are frozen once the campaign has ended
+        assert_eq!(
+            1,
+            campaign_info(&author.contract, 1)
+                .await
+                .value
+                .stretch_goals_reached
+        );
+    }
+
+    #[tokio::test]
+    async fn unpledges_after_deadline_when_target_is_not_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 10;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount - 1,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount - 1).await;
+
+        // The campaign has failed even though nobody has finalized it
+        advance_to(&user.wallet, deadline).await;
+        unpledge(&user.contract, 1, defaults.target_amount - 1).await;
+
+        assert_eq!(
+            defaults.target_amount - 1,
+            user.wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            campaign_info(&author.contract, 1).await.value.total_pledge
+        );
+    }
 }
 
 mod revert {
@@ -411,8 +638,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -429,8 +658,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -448,8 +679,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -462,7 +695,7 @@ mod revert {
     #[should_panic(expected = "Revert(42)")]
     async fn after_claimed() {
         let (author, user, asset, _, defaults) = setup().await;
-        let deadline = 6;
+        let deadline = 7;
 
         mint(
             &asset.contract,
@@ -473,8 +706,10 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             deadline,
+            &defaults.metadata,
             defaults.target_amount,
         )
         .await;
@@ -486,6 +721,36 @@ mod revert {
         unpledge(&user.contract, 1, defaults.target_amount).await;
     }
 
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn after_finalized_as_succeeded() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 7;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        finalize(&user.contract, 1).await;
+
+        // Reverts
+        unpledge(&user.contract, 1, defaults.target_amount).await;
+    }
+
     #[tokio::test]
     #[should_panic(expected = "Revert(42)")]
     async fn when_user_has_not_pledged() {
@@ -494,11 +759,44 @@ mod revert {
         create_campaign(
             &author.contract,
             &defaults.asset_id,
-            &defaults.beneficiary,
+            &defaults.beneficiaries,
+            defaults.category,
             defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        unpledge(&user.contract, 1, defaults.target_amount).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn after_deadline_when_target_is_reached() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let deadline = 10;
+
+        mint(
+            &asset.contract,
             defaults.target_amount,
+            user.wallet.address(),
         )
         .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // The campaign has succeeded even though nobody has finalized it
+        advance_to(&user.wallet, deadline).await;
 
         // Reverts
         unpledge(&user.contract, 1, defaults.target_amount).await;
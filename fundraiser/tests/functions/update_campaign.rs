@@ -0,0 +1,460 @@
+use crate::utils::{
+    abi_calls::{
+        add_stretch_goal, campaign_info, cancel_campaign, create_campaign, pledge, sponsor,
+        update_campaign,
+    },
+    test_helpers::{mint, setup, single_beneficiary},
+    CampaignMetadata, Identity, TOTAL_SHARES,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn updates_beneficiaries() {
+        let (author, _, _, _, defaults) = setup().await;
+        let beneficiaries = single_beneficiary(Identity::Address(author.wallet.address()));
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            defaults.beneficiaries,
+            campaign_info(&author.contract, 1).await.value.beneficiaries
+        );
+
+        update_campaign(
+            &author.contract,
+            1,
+            &beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        let info = campaign_info(&author.contract, 1).await.value;
+        assert_eq!(beneficiaries, info.beneficiaries);
+        assert_eq!(defaults.deadline, info.deadline);
+        assert_eq!(defaults.metadata, info.metadata);
+        assert_eq!(defaults.target_amount, info.target_amount);
+    }
+
+    #[tokio::test]
+    async fn updates_deadline() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            defaults.deadline,
+            campaign_info(&author.contract, 1).await.value.deadline
+        );
+
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline * 2,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        let info = campaign_info(&author.contract, 1).await.value;
+        assert_eq!(defaults.beneficiaries, info.beneficiaries);
+        assert_eq!(defaults.deadline * 2, info.deadline);
+        assert_eq!(defaults.metadata, info.metadata);
+        assert_eq!(defaults.target_amount, info.target_amount);
+    }
+
+    #[tokio::test]
+    async fn updates_metadata() {
+        let (author, _, _, _, defaults) = setup().await;
+        let metadata = CampaignMetadata {
+            hash: [2u8; 32],
+            uri: format!("{:<64}", "https://fuel.network/campaigns/2"),
+        };
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            defaults.metadata,
+            campaign_info(&author.contract, 1).await.value.metadata
+        );
+
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        let info = campaign_info(&author.contract, 1).await.value;
+        assert_eq!(defaults.beneficiaries, info.beneficiaries);
+        assert_eq!(defaults.deadline, info.deadline);
+        assert_eq!(metadata, info.metadata);
+        assert_eq!(defaults.target_amount, info.target_amount);
+    }
+
+    #[tokio::test]
+    async fn updates_target_amount() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        assert_eq!(
+            defaults.target_amount,
+            campaign_info(&author.contract, 1).await.value.target_amount
+        );
+
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+
+        let info = campaign_info(&author.contract, 1).await.value;
+        assert_eq!(defaults.beneficiaries, info.beneficiaries);
+        assert_eq!(defaults.deadline, info.deadline);
+        assert_eq!(defaults.metadata, info.metadata);
+        assert_eq!(defaults.target_amount * 2, info.target_amount);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            0,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_greater_than_number_of_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_author() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        update_campaign(
+            &user.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_cancelled() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 1).await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_pledged() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_campaign_is_sponsored() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        sponsor(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_deadline_is_in_the_past() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            0,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_amount_is_zero() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            0,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_shares_do_not_sum_to_total() {
+        let (author, _, _, _, defaults) = setup().await;
+        let mut beneficiaries = defaults.beneficiaries.clone();
+
+        beneficiaries[0].share = TOTAL_SHARES + 1;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_target_amount_is_not_below_stretch_goals() {
+        let (author, _, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        add_stretch_goal(&author.contract, 1, defaults.target_amount * 2).await;
+
+        // Reverts
+        update_campaign(
+            &author.contract,
+            1,
+            &defaults.beneficiaries,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount * 2,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,156 @@
+use crate::utils::{
+    abi_calls::{cancel_campaign, create_campaign, user_campaigns},
+    test_helpers::{all_user_campaigns, setup},
+    CampaignFilter, CampaignStatus, Identity, PAGE_SIZE,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_page() {
+        let (author, _, _, _, _) = setup().await;
+
+        let page = user_campaigns(
+            &author.contract,
+            Identity::Address(author.wallet.address()),
+            1,
+            PAGE_SIZE,
+            CampaignFilter::All(),
+        )
+        .await;
+
+        assert_eq!(PAGE_SIZE as usize, page.len());
+        assert!(page.iter().all(|entry| entry.id == 0));
+    }
+
+    #[tokio::test]
+    async fn returns_only_campaigns_created_by_user() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &user.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+
+        let page = user_campaigns(
+            &author.contract,
+            Identity::Address(author.wallet.address()),
+            1,
+            PAGE_SIZE,
+            CampaignFilter::All(),
+        )
+        .await;
+
+        assert_eq!(1, page[0].id);
+        assert_eq!(3, page[1].id);
+        assert!(page[2..].iter().all(|entry| entry.id == 0));
+    }
+
+    #[tokio::test]
+    async fn filters_cancelled_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+        let user = Identity::Address(author.wallet.address());
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        cancel_campaign(&author.contract, 2).await;
+
+        let active = all_user_campaigns(
+            &author.contract,
+            user.clone(),
+            CampaignFilter::Status(CampaignStatus::Active()),
+        )
+        .await;
+        let cancelled = all_user_campaigns(
+            &author.contract,
+            user,
+            CampaignFilter::Status(CampaignStatus::Cancelled()),
+        )
+        .await;
+
+        assert_eq!(1, active.len());
+        assert_eq!(1, active[0].id);
+        assert_eq!(1, cancelled.len());
+        assert_eq!(2, cancelled[0].id);
+        assert_eq!(CampaignStatus::Cancelled(), cancelled[0].info.status);
+    }
+
+    #[tokio::test]
+    async fn pages_through_all_campaigns() {
+        let (author, _, _, _, defaults) = setup().await;
+        let total = PAGE_SIZE + 2;
+
+        for _ in 0..total {
+            create_campaign(
+                &author.contract,
+                &defaults.asset_id,
+                &defaults.beneficiaries,
+                defaults.category,
+                defaults.deadline,
+                &defaults.metadata,
+                defaults.target_amount,
+            )
+            .await;
+        }
+
+        let entries = all_user_campaigns(
+            &author.contract,
+            Identity::Address(author.wallet.address()),
+            CampaignFilter::All(),
+        )
+        .await;
+
+        assert_eq!(total as usize, entries.len());
+        assert!(entries
+            .iter()
+            .zip(1..=total)
+            .all(|(entry, id)| entry.id == id));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::utils::{
+    abi_calls::{cancel_campaign, create_campaign, pledge, user_pledges},
+    test_helpers::{all_user_pledges, mint, setup},
+    CampaignFilter, CampaignStatus, Identity, PAGE_SIZE,
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_page() {
+        let (_, user, _, _, _) = setup().await;
+
+        let page = user_pledges(
+            &user.contract,
+            Identity::Address(user.wallet.address()),
+            1,
+            PAGE_SIZE,
+            CampaignFilter::All(),
+        )
+        .await;
+
+        assert_eq!(PAGE_SIZE as usize, page.len());
+        assert!(page.iter().all(|pledge| pledge.id == 0));
+    }
+
+    #[tokio::test]
+    async fn returns_page_of_pledges() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount * 2,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 2, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+
+        let page = user_pledges(
+            &user.contract,
+            Identity::Address(user.wallet.address()),
+            1,
+            PAGE_SIZE,
+            CampaignFilter::All(),
+        )
+        .await;
+
+        assert_eq!(2, page[0].id);
+        assert_eq!(defaults.target_amount, page[0].amount);
+        assert_eq!(1, page[1].id);
+        assert_eq!(defaults.target_amount / 2, page[1].amount);
+        assert!(page[2..].iter().all(|pledge| pledge.id == 0));
+    }
+
+    #[tokio::test]
+    async fn filters_pledges_by_campaign_state() {
+        let (author, user, asset, _, defaults) = setup().await;
+        let backer = Identity::Address(user.wallet.address());
+
+        mint(
+            &asset.contract,
+            defaults.target_amount * 2,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        pledge(&user.contract, 2, &asset, defaults.target_amount).await;
+        cancel_campaign(&author.contract, 1).await;
+
+        let active = all_user_pledges(
+            &user.contract,
+            backer.clone(),
+            CampaignFilter::Status(CampaignStatus::Active()),
+        )
+        .await;
+        let cancelled = all_user_pledges(
+            &user.contract,
+            backer,
+            CampaignFilter::Status(CampaignStatus::Cancelled()),
+        )
+        .await;
+
+        assert_eq!(1, active.len());
+        assert_eq!(2, active[0].id);
+        assert_eq!(1, cancelled.len());
+        assert_eq!(1, cancelled[0].id);
+    }
+}
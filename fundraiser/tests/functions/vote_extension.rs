@@ -0,0 +1,227 @@
+use crate::utils::{
+    abi_calls::{
+        campaign_info, create_campaign, extension_info, pledge, request_extension, unpledge,
+        vote_extension,
+    },
+    test_helpers::{mint, setup},
+};
+use fuels::signers::Signer;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn extends_deadline_with_majority() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount / 2).await;
+        request_extension(&author.contract, 1, 50).await;
+
+        vote_extension(&user.contract, 1).await;
+
+        let info = extension_info(&author.contract, 1).await;
+        assert_eq!(true, info.approved);
+        assert_eq!(defaults.target_amount / 2, info.yes_votes);
+        assert_eq!(
+            defaults.deadline + 50,
+            campaign_info(&author.contract, 1).await.value.deadline
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_extend_without_majority() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 100).await;
+        pledge(&author.contract, 1, &asset, 100).await;
+        request_extension(&author.contract, 1, 50).await;
+
+        vote_extension(&user.contract, 1).await;
+
+        // Half of the pledged amount is not a majority
+        let info = extension_info(&author.contract, 1).await;
+        assert_eq!(false, info.approved);
+        assert_eq!(100, info.yes_votes);
+        assert_eq!(
+            defaults.deadline,
+            campaign_info(&author.contract, 1).await.value.deadline
+        );
+
+        vote_extension(&author.contract, 1).await;
+
+        assert_eq!(true, extension_info(&author.contract, 1).await.approved);
+        assert_eq!(
+            defaults.deadline + 50,
+            campaign_info(&author.contract, 1).await.value.deadline
+        );
+    }
+
+    #[tokio::test]
+    async fn unpledging_reduces_vote() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 100).await;
+        pledge(&author.contract, 1, &asset, 100).await;
+        request_extension(&author.contract, 1, 50).await;
+        vote_extension(&user.contract, 1).await;
+
+        unpledge(&user.contract, 1, 40).await;
+
+        assert_eq!(60, extension_info(&author.contract, 1).await.yes_votes);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_id_is_zero() {
+        let (_, user, _, _, _) = setup().await;
+
+        // Reverts
+        vote_extension(&user.contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_extension_not_requested() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+
+        // Reverts
+        vote_extension(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_not_pledged() {
+        let (author, user, _, _, defaults) = setup().await;
+
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        request_extension(&author.contract, 1, 50).await;
+
+        // Reverts
+        vote_extension(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_user_has_already_voted() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 100).await;
+        pledge(&author.contract, 1, &asset, 100).await;
+        request_extension(&author.contract, 1, 50).await;
+        vote_extension(&user.contract, 1).await;
+
+        // Reverts
+        vote_extension(&user.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_extension_already_approved() {
+        let (author, user, asset, _, defaults) = setup().await;
+
+        mint(&asset.contract, 100, user.wallet.address()).await;
+        mint(&asset.contract, 100, author.wallet.address()).await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            defaults.deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, 100).await;
+        request_extension(&author.contract, 1, 50).await;
+        vote_extension(&user.contract, 1).await;
+        pledge(&author.contract, 1, &asset, 100).await;
+
+        // Reverts
+        vote_extension(&author.contract, 1).await;
+    }
+}
@@ -0,0 +1,106 @@
+use crate::utils::{
+    abi_calls::{accrued_fees, claim_pledges, constructor, create_campaign, pledge, withdraw_fees},
+    test_helpers::{mint, setup, setup_uninitialized},
+    Identity,
+};
+use fuels::{signers::Signer, tx::AssetId};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn withdraws_accrued_fees() {
+        let (author, user, asset, _, defaults) = setup_uninitialized().await;
+        let deadline = 7;
+
+        constructor(
+            &author.contract,
+            250,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // 512 * 250 / 10_000 rounds down to 12
+        assert_eq!(12, accrued_fees(&author.contract, &asset.id).await);
+
+        withdraw_fees(&author.contract, &asset.id).await;
+
+        assert_eq!(0, accrued_fees(&author.contract, &asset.id).await);
+        assert_eq!(
+            12,
+            author
+                .wallet
+                .get_asset_balance(&AssetId::from(*asset.id))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_sender_is_not_fee_recipient() {
+        let (author, user, asset, _, defaults) = setup_uninitialized().await;
+        let deadline = 7;
+
+        constructor(
+            &author.contract,
+            250,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+        mint(
+            &asset.contract,
+            defaults.target_amount,
+            user.wallet.address(),
+        )
+        .await;
+        create_campaign(
+            &author.contract,
+            &defaults.asset_id,
+            &defaults.beneficiaries,
+            defaults.category,
+            deadline,
+            &defaults.metadata,
+            defaults.target_amount,
+        )
+        .await;
+        pledge(&user.contract, 1, &asset, defaults.target_amount).await;
+        claim_pledges(&author.contract, 1).await;
+
+        // Reverts
+        withdraw_fees(&user.contract, &asset.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert(42)")]
+    async fn when_no_fees_have_accrued() {
+        let (author, _, asset, _, _) = setup().await;
+
+        // Reverts
+        withdraw_fees(&author.contract, &asset.id).await;
+    }
+}
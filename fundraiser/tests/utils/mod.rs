@@ -8,16 +8,36 @@ use fuels_abigen_macro::abigen;
 // Load abi from json
 abigen!(Fundraiser, "out/debug/fundraiser-abi.json");
 abigen!(Asset, "tests/artifacts/asset/out/debug/asset-abi.json");
+abigen!(Nft, "../NFT/out/debug/NFT-abi.json");
+abigen!(Oracle, "tests/artifacts/oracle/out/debug/oracle-abi.json");
+
+/// The maximum number of blocks by which the deadline of a campaign can be extended
+pub const MAX_EXTENSION: u64 = 10_000;
+
+/// The scale of the prices reported by an oracle
+pub const PRICE_PRECISION: u64 = 1_000_000_000;
+
+/// The number of blocks after the deadline during which the price of a quote target can be recorded
+pub const PRICE_RECORDING_PERIOD: u64 = 100;
+
+/// Maximum number of entries returned by a single page of campaigns or pledges
+pub const PAGE_SIZE: u64 = 10;
+
+/// The sum of the shares of all beneficiaries of a campaign in basis points
+pub const TOTAL_SHARES: u64 = 10_000;
 
 pub struct DefaultParameters {
     pub asset_id: ContractId,
-    pub beneficiary: Identity,
+    pub beneficiaries: Vec<Beneficiary>,
+    pub category: u64,
     pub deadline: u64,
+    pub metadata: CampaignMetadata,
     pub target_amount: u64,
 }
 
 pub struct Metadata {
     pub contract: Fundraiser,
+    pub contract_id: ContractId,
     pub wallet: LocalWallet,
 }
 
@@ -26,32 +46,81 @@ pub struct MetaAsset {
     pub id: ContractId,
 }
 
+pub struct MetaNft {
+    pub contract: Nft,
+    pub id: ContractId,
+}
+
+pub struct MetaOracle {
+    pub contract: Oracle,
+    pub id: ContractId,
+}
+
 pub mod abi_calls {
 
     use super::*;
 
+    pub async fn constructor(
+        contract: &Fundraiser,
+        fee: u64,
+        fee_recipient: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .constructor(fee, fee_recipient)
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn create_campaign(
         contract: &Fundraiser,
         asset: &ContractId,
-        beneficiary: &Identity,
+        beneficiaries: &[Beneficiary],
+        category: u64,
         deadline: u64,
+        metadata: &CampaignMetadata,
         target_amount: u64,
     ) -> CallResponse<()> {
         contract
-            .create_campaign(asset.clone(), beneficiary.clone(), deadline, target_amount)
+            .create_campaign(
+                asset.clone(),
+                beneficiaries.to_vec(),
+                category,
+                deadline,
+                metadata.clone(),
+                target_amount,
+            )
             .call()
             .await
             .unwrap()
     }
 
     pub async fn cancel_campaign(contract: &Fundraiser, id: u64) -> CallResponse<()> {
-        contract.cancel_campaign(id).call().await.unwrap()
+        contract
+            .cancel_campaign(id)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
     }
 
     pub async fn claim_pledges(contract: &Fundraiser, id: u64) -> CallResponse<()> {
         contract
             .claim_pledges(id)
-            .append_variable_outputs(1)
+            .append_variable_outputs(5)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn claim_reward(
+        contract: &Fundraiser,
+        id: u64,
+        nft: &ContractId,
+    ) -> CallResponse<()> {
+        contract
+            .claim_reward(id)
+            .set_contracts(&[*nft])
             .call()
             .await
             .unwrap()
@@ -75,6 +144,38 @@ pub mod abi_calls {
             .unwrap()
     }
 
+    pub async fn pledge_for(
+        contract: &Fundraiser,
+        id: u64,
+        user: Identity,
+        asset: &MetaAsset,
+        amount: u64,
+    ) -> CallResponse<()> {
+        let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
+        let call_params = CallParameters::new(Some(amount), Some(AssetId::from(*asset.id)));
+
+        contract
+            .pledge_for(id, user)
+            .tx_params(tx_params)
+            .call_params(call_params)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn transfer_pledge(
+        contract: &Fundraiser,
+        id: u64,
+        to: Identity,
+        amount: u64,
+    ) -> CallResponse<()> {
+        contract
+            .transfer_pledge(id, to, amount)
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn unpledge(contract: &Fundraiser, id: u64, amount: u64) -> CallResponse<()> {
         contract
             .unpledge(id, amount)
@@ -84,6 +185,257 @@ pub mod abi_calls {
             .unwrap()
     }
 
+    pub async fn update_campaign(
+        contract: &Fundraiser,
+        id: u64,
+        beneficiaries: &[Beneficiary],
+        deadline: u64,
+        metadata: &CampaignMetadata,
+        target_amount: u64,
+    ) -> CallResponse<()> {
+        contract
+            .update_campaign(
+                id,
+                beneficiaries.to_vec(),
+                deadline,
+                metadata.clone(),
+                target_amount,
+            )
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn add_reward_tier(
+        contract: &Fundraiser,
+        id: u64,
+        minimum_pledge: u64,
+        nft: &ContractId,
+        quantity: u64,
+    ) -> CallResponse<()> {
+        contract
+            .add_reward_tier(id, minimum_pledge, *nft, quantity)
+            .set_contracts(&[*nft])
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn select_reward_tier(contract: &Fundraiser, id: u64, tier: u64) -> CallResponse<()> {
+        contract.select_reward_tier(id, tier).call().await.unwrap()
+    }
+
+    pub async fn set_milestones(
+        contract: &Fundraiser,
+        id: u64,
+        tranches: u64,
+        voting_period: u64,
+    ) -> CallResponse<()> {
+        contract
+            .set_milestones(id, tranches, voting_period)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn propose_tranche(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.propose_tranche(id).call().await.unwrap()
+    }
+
+    pub async fn vote_tranche(contract: &Fundraiser, id: u64, approve: bool) -> CallResponse<()> {
+        contract.vote_tranche(id, approve).call().await.unwrap()
+    }
+
+    pub async fn release_tranche(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract
+            .release_tranche(id)
+            .append_variable_outputs(4)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn abandon_milestones(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.abandon_milestones(id).call().await.unwrap()
+    }
+
+    pub async fn reclaim_remainder(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract
+            .reclaim_remainder(id)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn refund_batch(
+        contract: &Fundraiser,
+        id: u64,
+        start: u64,
+        count: u64,
+    ) -> CallResponse<()> {
+        contract
+            .refund_batch(id, start, count)
+            .append_variable_outputs(count.min(10))
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn sponsor(
+        contract: &Fundraiser,
+        id: u64,
+        asset: &MetaAsset,
+        amount: u64,
+    ) -> CallResponse<()> {
+        let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
+        let call_params = CallParameters::new(Some(amount), Some(AssetId::from(*asset.id)));
+
+        contract
+            .sponsor(id)
+            .tx_params(tx_params)
+            .call_params(call_params)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn refund_sponsor(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract
+            .refund_sponsor(id)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn withdraw_fees(contract: &Fundraiser, asset: &ContractId) -> CallResponse<()> {
+        contract
+            .withdraw_fees(*asset)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_private(contract: &Fundraiser, id: u64, private: bool) -> CallResponse<()> {
+        contract.set_private(id, private).call().await.unwrap()
+    }
+
+    pub async fn add_to_allowlist(
+        contract: &Fundraiser,
+        id: u64,
+        user: Identity,
+    ) -> CallResponse<()> {
+        contract.add_to_allowlist(id, user).call().await.unwrap()
+    }
+
+    pub async fn remove_from_allowlist(
+        contract: &Fundraiser,
+        id: u64,
+        user: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .remove_from_allowlist(id, user)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn subscribe(
+        contract: &Fundraiser,
+        id: u64,
+        asset: &MetaAsset,
+        balance: u64,
+        amount: u64,
+        period: u64,
+    ) -> CallResponse<()> {
+        let tx_params = TxParameters::new(None, Some(1_000_000), None, None);
+        let call_params = CallParameters::new(Some(balance), Some(AssetId::from(*asset.id)));
+
+        contract
+            .subscribe(id, amount, period)
+            .tx_params(tx_params)
+            .call_params(call_params)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn pull_subscription(
+        contract: &Fundraiser,
+        subscription_id: u64,
+    ) -> CallResponse<()> {
+        contract
+            .pull_subscription(subscription_id)
+            .append_variable_outputs(4)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn cancel_subscription(
+        contract: &Fundraiser,
+        subscription_id: u64,
+    ) -> CallResponse<()> {
+        contract
+            .cancel_subscription(subscription_id)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn request_extension(
+        contract: &Fundraiser,
+        id: u64,
+        extension: u64,
+    ) -> CallResponse<()> {
+        contract
+            .request_extension(id, extension)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn vote_extension(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.vote_extension(id).call().await.unwrap()
+    }
+
+    pub async fn add_stretch_goal(contract: &Fundraiser, id: u64, target: u64) -> CallResponse<()> {
+        contract.add_stretch_goal(id, target).call().await.unwrap()
+    }
+
+    pub async fn set_quote_target(
+        contract: &Fundraiser,
+        id: u64,
+        oracle: &ContractId,
+        target: u64,
+    ) -> CallResponse<()> {
+        contract
+            .set_quote_target(id, *oracle, target)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn record_price(
+        contract: &Fundraiser,
+        id: u64,
+        oracle: &ContractId,
+    ) -> CallResponse<()> {
+        contract
+            .record_price(id)
+            .set_contracts(&[*oracle])
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn finalize(contract: &Fundraiser, id: u64) -> CallResponse<()> {
+        contract.finalize(id).call().await.unwrap()
+    }
+
     pub async fn total_campaigns(contract: &Fundraiser) -> u64 {
         contract.total_campaigns().call().await.unwrap().value
     }
@@ -131,13 +483,253 @@ pub mod abi_calls {
     pub async fn asset_info_by_count(contract: &Fundraiser, id: u64) -> CallResponse<AssetInfo> {
         contract.asset_info_by_count(id).call().await.unwrap()
     }
+
+    pub async fn reward_tier_count(contract: &Fundraiser, id: u64) -> u64 {
+        contract.reward_tier_count(id).call().await.unwrap().value
+    }
+
+    pub async fn reward_tier(contract: &Fundraiser, id: u64, tier: u64) -> RewardTier {
+        contract.reward_tier(id, tier).call().await.unwrap().value
+    }
+
+    pub async fn milestone_info(contract: &Fundraiser, id: u64) -> MilestoneInfo {
+        contract.milestone_info(id).call().await.unwrap().value
+    }
+
+    pub async fn reward_selection(
+        contract: &Fundraiser,
+        id: u64,
+        user: Identity,
+    ) -> RewardSelection {
+        contract
+            .reward_selection(id, user)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn campaigns(
+        contract: &Fundraiser,
+        start: u64,
+        count: u64,
+        filter: CampaignFilter,
+    ) -> Vec<CampaignEntry> {
+        contract
+            .campaigns(start, count, filter)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn user_campaigns(
+        contract: &Fundraiser,
+        user: Identity,
+        start: u64,
+        count: u64,
+        filter: CampaignFilter,
+    ) -> Vec<CampaignEntry> {
+        contract
+            .user_campaigns(user, start, count, filter)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn user_pledges(
+        contract: &Fundraiser,
+        user: Identity,
+        start: u64,
+        count: u64,
+        filter: CampaignFilter,
+    ) -> Vec<Pledge> {
+        contract
+            .user_pledges(user, start, count, filter)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn backer_count(contract: &Fundraiser, id: u64) -> u64 {
+        contract.backer_count(id).call().await.unwrap().value
+    }
+
+    pub async fn backer(contract: &Fundraiser, id: u64, index: u64) -> Identity {
+        contract.backer(id, index).call().await.unwrap().value
+    }
+
+    pub async fn sponsor_pool(contract: &Fundraiser, id: u64) -> SponsorPool {
+        contract.sponsor_pool(id).call().await.unwrap().value
+    }
+
+    pub async fn sponsor_matched(contract: &Fundraiser, id: u64, user: Identity) -> u64 {
+        contract
+            .sponsor_matched(id, user)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn fee(contract: &Fundraiser) -> u64 {
+        contract.fee().call().await.unwrap().value
+    }
+
+    pub async fn fee_recipient(contract: &Fundraiser) -> Identity {
+        contract.fee_recipient().call().await.unwrap().value
+    }
+
+    pub async fn accrued_fees(contract: &Fundraiser, asset: &ContractId) -> u64 {
+        contract.accrued_fees(*asset).call().await.unwrap().value
+    }
+
+    pub async fn is_private(contract: &Fundraiser, id: u64) -> bool {
+        contract.is_private(id).call().await.unwrap().value
+    }
+
+    pub async fn allowlisted(contract: &Fundraiser, id: u64, user: Identity) -> bool {
+        contract.allowlisted(id, user).call().await.unwrap().value
+    }
+
+    pub async fn active_campaign_count(contract: &Fundraiser) -> u64 {
+        contract.active_campaign_count().call().await.unwrap().value
+    }
+
+    pub async fn active_campaign(contract: &Fundraiser, index: u64) -> u64 {
+        contract.active_campaign(index).call().await.unwrap().value
+    }
+
+    pub async fn status_campaign_count(contract: &Fundraiser, status: CampaignStatus) -> u64 {
+        contract
+            .status_campaign_count(status)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn status_campaign(contract: &Fundraiser, status: CampaignStatus, index: u64) -> u64 {
+        contract
+            .status_campaign(status, index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn category_campaign_count(contract: &Fundraiser, category: u64) -> u64 {
+        contract
+            .category_campaign_count(category)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn category_campaign(contract: &Fundraiser, category: u64, index: u64) -> u64 {
+        contract
+            .category_campaign(category, index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn extension_info(contract: &Fundraiser, id: u64) -> ExtensionInfo {
+        contract.extension_info(id).call().await.unwrap().value
+    }
+
+    pub async fn quote_target(contract: &Fundraiser, id: u64) -> QuoteTarget {
+        contract.quote_target(id).call().await.unwrap().value
+    }
+
+    pub async fn author_stats(contract: &Fundraiser, author: Identity) -> AuthorStats {
+        contract.author_stats(author).call().await.unwrap().value
+    }
+
+    pub async fn author_raised(contract: &Fundraiser, author: Identity, asset: &ContractId) -> u64 {
+        contract
+            .author_raised(author, *asset)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn subscription_count(contract: &Fundraiser) -> u64 {
+        contract.subscription_count().call().await.unwrap().value
+    }
+
+    pub async fn subscription(contract: &Fundraiser, subscription_id: u64) -> Subscription {
+        contract
+            .subscription(subscription_id)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
 }
 
 pub mod test_helpers {
 
     use super::*;
+    use abi_calls::{
+        campaigns, constructor, pledge_count, total_campaigns, user_campaign_count, user_campaigns,
+        user_pledges,
+    };
+
+    /// Produces empty transfers until the latest block is at `height` so that the next
+    /// transaction is executed at `height`
+    pub async fn advance_to(wallet: &LocalWallet, height: u64) {
+        assert!(
+            block_height(wallet).await <= height,
+            "the block height is already past {}",
+            height
+        );
+
+        while block_height(wallet).await < height {
+            wallet
+                .transfer(
+                    &wallet.address(),
+                    1,
+                    AssetId::default(),
+                    TxParameters::default(),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    pub async fn block_height(wallet: &LocalWallet) -> u64 {
+        wallet
+            .get_provider()
+            .unwrap()
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .height
+            .0
+    }
 
     pub async fn setup() -> (Metadata, Metadata, MetaAsset, MetaAsset, DefaultParameters) {
+        let (author, user, asset, asset2, defaults) = setup_uninitialized().await;
+
+        constructor(
+            &author.contract,
+            0,
+            Identity::Address(author.wallet.address()),
+        )
+        .await;
+
+        (author, user, asset, asset2, defaults)
+    }
+
+    pub async fn setup_uninitialized(
+    ) -> (Metadata, Metadata, MetaAsset, MetaAsset, DefaultParameters) {
         let num_wallets = 3;
         let coins_per_wallet = 1;
         let amount_per_coin = 1_000_000;
@@ -181,11 +773,13 @@ pub mod test_helpers {
 
         let author = Metadata {
             contract: Fundraiser::new(id.to_string(), author_wallet.clone()),
+            contract_id: id,
             wallet: author_wallet,
         };
 
         let user = Metadata {
             contract: Fundraiser::new(id.to_string(), user_wallet.clone()),
+            contract_id: id,
             wallet: user_wallet.clone(),
         };
 
@@ -201,14 +795,36 @@ pub mod test_helpers {
 
         let defaults = DefaultParameters {
             asset_id,
-            beneficiary: Identity::Address(user_wallet.address()),
+            beneficiaries: single_beneficiary(Identity::Address(user_wallet.address())),
+            category: 1,
             deadline: 100,
+            metadata: CampaignMetadata {
+                hash: [1u8; 32],
+                uri: format!("{:<64}", "https://fuel.network/campaigns/1"),
+            },
             target_amount: 512,
         };
 
         (author, user, asset, asset2, defaults)
     }
 
+    pub fn single_beneficiary(recipient: Identity) -> Vec<Beneficiary> {
+        let unused = Beneficiary {
+            recipient: Identity::Address(Address::zeroed()),
+            share: 0,
+        };
+
+        vec![
+            Beneficiary {
+                recipient,
+                share: TOTAL_SHARES,
+            },
+            unused.clone(),
+            unused.clone(),
+            unused,
+        ]
+    }
+
     pub async fn mint(contract: &Asset, amount: u64, address: Address) -> bool {
         contract
             .mint_and_send_to_address(amount, address)
@@ -218,4 +834,119 @@ pub mod test_helpers {
             .unwrap()
             .value
     }
+
+    pub async fn deploy_nft(
+        wallet: &LocalWallet,
+        admin: Identity,
+        max_supply: u64,
+        salt: u8,
+    ) -> MetaNft {
+        let id = Contract::deploy_with_salt(
+            "../NFT/out/debug/NFT.bin",
+            wallet,
+            TxParameters::default(),
+            Salt::from([salt; 32]),
+        )
+        .await
+        .unwrap();
+
+        let contract = Nft::new(id.to_string(), wallet.clone());
+        contract
+            .constructor(true, admin, max_supply)
+            .call()
+            .await
+            .unwrap();
+
+        MetaNft { contract, id }
+    }
+
+    pub async fn deploy_oracle(wallet: &LocalWallet) -> MetaOracle {
+        let id = Contract::deploy(
+            "tests/artifacts/oracle/out/debug/oracle.bin",
+            wallet,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+
+        MetaOracle {
+            contract: Oracle::new(id.to_string(), wallet.clone()),
+            id,
+        }
+    }
+
+    pub async fn set_price(oracle: &MetaOracle, asset: &ContractId, price: u64) {
+        oracle
+            .contract
+            .set_price(*asset, price)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    pub async fn nft_balance(nft: &MetaNft, owner: Identity) -> u64 {
+        nft.contract.balance_of(owner).call().await.unwrap().value
+    }
+
+    pub async fn all_campaigns(
+        contract: &Fundraiser,
+        filter: CampaignFilter,
+    ) -> Vec<CampaignEntry> {
+        let total = total_campaigns(contract).await;
+        let mut entries = vec![];
+
+        for start in (1..=total).step_by(PAGE_SIZE as usize) {
+            let count = PAGE_SIZE.min(total - start + 1);
+            entries.extend(
+                campaigns(contract, start, count, filter.clone())
+                    .await
+                    .into_iter()
+                    .filter(|entry| entry.id != 0),
+            );
+        }
+
+        entries
+    }
+
+    pub async fn all_user_campaigns(
+        contract: &Fundraiser,
+        user: Identity,
+        filter: CampaignFilter,
+    ) -> Vec<CampaignEntry> {
+        let total = user_campaign_count(contract, user.clone()).await;
+        let mut entries = vec![];
+
+        for start in (1..=total).step_by(PAGE_SIZE as usize) {
+            let count = PAGE_SIZE.min(total - start + 1);
+            entries.extend(
+                user_campaigns(contract, user.clone(), start, count, filter.clone())
+                    .await
+                    .into_iter()
+                    .filter(|entry| entry.id != 0),
+            );
+        }
+
+        entries
+    }
+
+    pub async fn all_user_pledges(
+        contract: &Fundraiser,
+        user: Identity,
+        filter: CampaignFilter,
+    ) -> Vec<Pledge> {
+        let total = pledge_count(contract, user.clone()).await;
+        let mut pledges = vec![];
+
+        for start in (1..=total).step_by(PAGE_SIZE as usize) {
+            let count = PAGE_SIZE.min(total - start + 1);
+            pledges.extend(
+                user_pledges(contract, user.clone(), start, count, filter.clone())
+                    .await
+                    .into_iter()
+                    .filter(|pledge| pledge.id != 0),
+            );
+        }
+
+        pledges
+    }
 }